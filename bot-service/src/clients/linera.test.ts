@@ -14,6 +14,8 @@ describe('LineraClient', () => {
       endpoint: 'http://localhost:8080/graphql',
       applicationId: 'test-app-id',
       chainId: 'test-chain-id',
+      botId: 'momentum-bot',
+      asset: 'ETHUSDT',
       timeout: 5000,
     };
     client = new LineraClient(config);
@@ -37,6 +39,8 @@ describe('LineraClient', () => {
         endpoint: 'http://localhost:8080',
         applicationId: 'app',
         chainId: 'chain',
+        botId: 'bot',
+        asset: 'ETHUSDT',
       });
       expect(clientWithoutTimeout.getConfig().timeout).toBeUndefined();
     });
@@ -64,7 +68,7 @@ describe('LineraClient', () => {
         json: async () => mockResponse,
       } as Response);
 
-      const result = await client.submitPrediction(signal);
+      const result = await client.submitPrediction(signal, 2400);
 
       expect(result.success).toBe(true);
       expect(result.certificateHash).toBe('hash123');
@@ -75,6 +79,11 @@ describe('LineraClient', () => {
           headers: { 'Content-Type': 'application/json' },
         })
       );
+
+      const body = JSON.parse(vi.mocked(fetch).mock.calls[0][1]?.body as string);
+      expect(body.variables.botId).toBe('momentum-bot');
+      expect(body.variables.asset).toBe('ETHUSDT');
+      expect(body.variables.referencePriceMicro).toBe('2400000000');
     });
 
     it('should handle HTTP errors', async () => {
@@ -92,7 +101,7 @@ describe('LineraClient', () => {
         statusText: 'Internal Server Error',
       } as Response);
 
-      const result = await client.submitPrediction(signal);
+      const result = await client.submitPrediction(signal, 2400);
 
       expect(result.success).toBe(false);
       expect(result.error).toContain('500');
@@ -114,7 +123,7 @@ describe('LineraClient', () => {
         }),
       } as Response);
 
-      const result = await client.submitPrediction(signal);
+      const result = await client.submitPrediction(signal, 2400);
 
       expect(result.success).toBe(false);
       expect(result.error).toContain('GraphQL errors');
@@ -135,7 +144,7 @@ describe('LineraClient', () => {
         })
       );
 
-      const result = await client.submitPrediction(signal);
+      const result = await client.submitPrediction(signal, 2400);
 
       expect(result.success).toBe(false);
       expect(result.error).toBeTruthy();
@@ -183,7 +192,6 @@ describe('LineraClient', () => {
     it('should query bot state successfully', async () => {
       const mockState = {
         data: {
-          botId: 'momentum-bot',
           latestSignal: {
             timestamp: Date.now(),
             action: 'BUY',
@@ -212,6 +220,8 @@ describe('LineraClient', () => {
 
       expect(state).toBeDefined();
       expect(state?.botId).toBe('momentum-bot');
+      const body = JSON.parse(vi.mocked(fetch).mock.calls[0][1]?.body as string);
+      expect(body.variables).toEqual({ botId: 'momentum-bot', asset: 'ETHUSDT' });
       expect(state?.followerCount).toBe(42);
       expect(state?.accuracy24h.directionalAccuracy).toBe(0.65); // Converted from 6500 bps to 0.65 decimal
       expect(state?.accuracy24h.rmse).toBe(50.5); // Converted from micro-USD to USD
@@ -270,7 +280,7 @@ describe('LineraClient', () => {
           json: async () => ({ data: { submitPrediction: true } }),
         } as Response);
 
-        const result = await client.submitPrediction(signal);
+        const result = await client.submitPrediction(signal, 2400);
         expect(result.success).toBe(true);
       }
 
@@ -46,6 +46,12 @@ export interface LineraConfig {
   applicationId: string;
   /** Chain ID where the application is deployed */
   chainId: string;
+  /** Bot to submit predictions for (the bot_id passed to CreateBot) */
+  botId: string;
+  /** Trading pair the bot predicts (e.g., "ETHUSDT") */
+  asset: string;
+  /** Optional prediction horizon in milliseconds */
+  horizonMs?: number;
   /** Optional timeout in milliseconds */
   timeout?: number;
 }
@@ -66,27 +72,41 @@ export interface OperationResponse {
 export class LineraClient {
   private config: LineraConfig;
   private readonly timeout: number;
+  private readonly horizonMs: number;
 
   constructor(config: LineraConfig) {
     this.config = config;
     this.timeout = config.timeout || 30_000; // 30 second default
+    this.horizonMs = config.horizonMs || 3_600_000; // 1 hour default
   }
 
   /**
    * Submit a new prediction signal to the contract
+   * @param referencePrice - Price in USD the prediction was made at
    */
-  async submitPrediction(signal: Signal): Promise<OperationResponse> {
+  async submitPrediction(
+    signal: Signal,
+    referencePrice: number
+  ): Promise<OperationResponse> {
     const mutation = `
       mutation SubmitPrediction(
+        $botId: String!
+        $asset: String!
         $timestamp: String!
+        $horizonMs: String!
         $action: Action!
+        $referencePriceMicro: String!
         $predictedPriceMicro: String!
         $confidenceBps: Int!
         $reasoning: String!
       ) {
         submitPrediction(
+          botId: $botId
+          asset: $asset
           timestamp: $timestamp
+          horizonMs: $horizonMs
           action: $action
+          referencePriceMicro: $referencePriceMicro
           predictedPriceMicro: $predictedPriceMicro
           confidenceBps: $confidenceBps
           reasoning: $reasoning
@@ -95,8 +115,12 @@ export class LineraClient {
     `;
 
     const variables = {
+      botId: this.config.botId,
+      asset: this.config.asset,
       timestamp: signal.timestamp.toString(),
+      horizonMs: this.horizonMs.toString(),
       action: this.actionToGraphQL(signal.action),
+      referencePriceMicro: toMicroUSD(referencePrice),
       predictedPriceMicro: toMicroUSD(signal.predicted_price),
       confidenceBps: toBasisPoints(signal.confidence),
       reasoning: signal.reasoning,
@@ -126,10 +150,14 @@ export class LineraClient {
   ): Promise<OperationResponse> {
     const mutation = `
       mutation ResolveSignal(
+        $botId: String!
+        $asset: String!
         $timestamp: String!
         $actualPriceMicro: String!
       ) {
         resolveSignal(
+          botId: $botId
+          asset: $asset
           timestamp: $timestamp
           actualPriceMicro: $actualPriceMicro
         )
@@ -137,6 +165,8 @@ export class LineraClient {
     `;
 
     const variables = {
+      botId: this.config.botId,
+      asset: this.config.asset,
       timestamp: timestamp.toString(),
       actualPriceMicro: toMicroUSD(actualPrice),
     };
@@ -172,9 +202,8 @@ export class LineraClient {
     followerCount: number;
   } | null> {
     const query = `
-      query GetBotState {
-        botId
-        latestSignal {
+      query GetBotState($botId: String!, $asset: String) {
+        latestSignal(botId: $botId, asset: $asset) {
           timestamp
           action
           predictedPriceMicro
@@ -182,24 +211,27 @@ export class LineraClient {
           reasoning
           actualPriceMicro
         }
-        accuracy24H {
+        accuracy24H(botId: $botId, asset: $asset) {
           rmseMicro
           directionalAccuracyBps
           totalPredictions
           correctPredictions
           lastUpdated
         }
-        followerCount
+        followerCount(botId: $botId)
       }
     `;
 
     try {
-      const response = await this.executeQuery(query, {});
+      const response = await this.executeQuery(query, {
+        botId: this.config.botId,
+        asset: this.config.asset,
+      });
       const data = response.data;
 
       // Convert contract data (micro-USD, basis points) to TypeScript types (USD, decimals)
       return {
-        botId: data.botId,
+        botId: this.config.botId,
         latestSignal: data.latestSignal ? {
           timestamp: data.latestSignal.timestamp,
           action: data.latestSignal.action,
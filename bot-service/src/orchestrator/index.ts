@@ -139,7 +139,7 @@ export class Orchestrator {
       // Step 3: Submit to Linera
       if (this.lineraClient) {
         console.log('[Orchestrator] Submitting to Linera contract...');
-        const result = await this.lineraClient.submitPrediction(signal);
+        const result = await this.lineraClient.submitPrediction(signal, snapshot.currentPrice);
         if (result.success) {
           console.log(`[Orchestrator] ✓ Submitted to Linera (hash: ${result.certificateHash})`);
         } else {
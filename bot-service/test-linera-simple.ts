@@ -12,6 +12,8 @@ const LINERA_CONFIG: LineraConfig = {
   endpoint: 'http://localhost:8081/chains/bc87caebb95cc08c152b77f31842273cc57c6984413f7a3ad3c2599772a26cb4/applications/cbb593ca83c1327d57cf63e9996dee36811b3d9576592536bca4d41791c2f0e7',
   applicationId: 'cbb593ca83c1327d57cf63e9996dee36811b3d9576592536bca4d41791c2f0e7',
   chainId: 'bc87caebb95cc08c152b77f31842273cc57c6984413f7a3ad3c2599772a26cb4',
+  botId: 'my-bot',
+  asset: 'ETHUSDT',
   timeout: 30_000,
 };
 
@@ -48,7 +50,7 @@ async function main() {
 
   // Submit to contract
   console.log('\n🔗 Submitting prediction to Linera contract...');
-  const submitResult = await lineraClient.submitPrediction(mockSignal);
+  const submitResult = await lineraClient.submitPrediction(mockSignal, 3500);
 
   if (!submitResult.success) {
     console.error(`  ❌ Failed to submit: ${submitResult.error}`);
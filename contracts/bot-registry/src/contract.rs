@@ -0,0 +1,340 @@
+#![cfg_attr(target_arch = "wasm32", no_main)]
+
+use bot_registry::{
+    BotEntry, BotKey, Message, Operation, RegistryError, RegistryParameters, RegistryState,
+};
+use linera_sdk::{
+    linera_base_types::WithContractAbi,
+    views::{RootView, View},
+    Contract, ContractRuntime,
+};
+
+/// Maximum length of a bot id shown on the leaderboard
+const MAX_BOT_ID_LEN: usize = 64;
+
+pub struct BotRegistryContract {
+    state: RegistryState,
+    runtime: ContractRuntime<Self>,
+}
+
+linera_sdk::contract!(BotRegistryContract);
+
+impl WithContractAbi for BotRegistryContract {
+    type Abi = bot_registry::RegistryAbi;
+}
+
+impl Contract for BotRegistryContract {
+    type Message = Message;
+    type InstantiationArgument = ();
+    type Parameters = RegistryParameters;
+    type EventValue = ();
+
+    async fn load(runtime: ContractRuntime<Self>) -> Self {
+        let state = RegistryState::load(runtime.root_view_storage_context())
+            .await
+            .expect("Failed to load state");
+        BotRegistryContract { state, runtime }
+    }
+
+    async fn instantiate(&mut self, _argument: ()) {
+        // Validate that the application parameters were configured correctly
+        self.runtime.application_parameters();
+    }
+
+    async fn execute_operation(&mut self, operation: Operation) -> Self::Response {
+        // Entries are keyed by the calling application, so an application can only report
+        // for its own bots
+        let application_id = self.runtime.authenticated_caller_id()
+            .ok_or(RegistryError::NotCalledByApplication)?;
+        let bot_state_module = self.runtime.application_parameters().bot_state_module;
+        if self.runtime.read_application_description(application_id).module_id != bot_state_module {
+            return Err(RegistryError::UnauthorizedApplication);
+        }
+        let bot_chain = self.runtime.chain_id();
+
+        let message = match operation {
+            Operation::Register { bot_id } => {
+                check_bot_id(&bot_id)?;
+                Message::Register { application_id, bot_id, bot_chain }
+            }
+            Operation::ReportMetrics { bot_id, report } => {
+                check_bot_id(&bot_id)?;
+                Message::ReportMetrics { application_id, bot_id, bot_chain, report }
+            }
+            Operation::Unregister { bot_id } => Message::Unregister { application_id, bot_id },
+        };
+
+        let registry_chain = self.runtime.application_creator_chain_id();
+        self.runtime.prepare_message(message).send_to(registry_chain);
+        Ok(())
+    }
+
+    async fn execute_message(&mut self, message: Self::Message) {
+        assert_eq!(
+            self.runtime.chain_id(),
+            self.runtime.application_creator_chain_id(),
+            "Registry entries are only kept on the registry chain"
+        );
+        let now = self.runtime.system_time().micros() / 1000; // Convert to ms
+
+        let (application_id, bot_id, bot_chain, report) = match message {
+            Message::Register { application_id, bot_id, bot_chain } => {
+                (application_id, bot_id, bot_chain, None)
+            }
+            Message::ReportMetrics { application_id, bot_id, bot_chain, report } => {
+                (application_id, bot_id, bot_chain, Some(report))
+            }
+            Message::Unregister { application_id, bot_id } => {
+                self.state.bots.remove(&BotKey { application_id, bot_id })
+                    .expect("Failed to remove registry entry");
+                return;
+            }
+        };
+
+        let key = BotKey {
+            application_id,
+            bot_id: bot_id.clone(),
+        };
+        let existing = self.state.bots.get(&key).await
+            .expect("Failed to read registry entry");
+        let mut entry = match existing {
+            Some(entry) => entry,
+            None if report.is_none() => BotEntry {
+                application_id,
+                bot_id,
+                chain_id: bot_chain,
+                registered_at: now,
+                last_reported_at: 0,
+                report: Default::default(),
+            },
+            // Reports from bots that are not registered, such as retired ones, are ignored
+            // so they are not listed again
+            None => return,
+        };
+        entry.chain_id = bot_chain;
+        if let Some(report) = report {
+            entry.report = report;
+            entry.last_reported_at = now;
+        }
+        self.state.bots.insert(&key, entry)
+            .expect("Failed to store registry entry");
+    }
+
+    async fn store(mut self) {
+        self.state.save().await.expect("Failed to save state");
+    }
+}
+
+/// Check that a bot id can be displayed on the leaderboard
+fn check_bot_id(bot_id: &str) -> Result<(), RegistryError> {
+    if bot_id.is_empty() || bot_id.len() > MAX_BOT_ID_LEN {
+        return Err(RegistryError::InvalidBotId);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use linera_sdk::{
+        linera_base_types::{
+            ApplicationDescription, ApplicationId, BlockHeight, ChainId, CryptoHash, ModuleId,
+            Timestamp, VmRuntime,
+        },
+        util::BlockingWait,
+        views::View,
+        Contract, ContractRuntime,
+    };
+
+    use bot_registry::{
+        BotEntry, BotKey, Message, MetricsReport, Operation, RegistryError, RegistryParameters,
+        WindowMetrics,
+    };
+
+    use super::{BotRegistryContract, RegistryState};
+
+    const NOW_MS: u64 = 3_000_000;
+
+    #[test]
+    fn test_operations_require_calling_application() {
+        let mut app = create_and_instantiate_app();
+
+        let result = app
+            .execute_operation(Operation::Register { bot_id: "momentum".to_string() })
+            .blocking_wait();
+        assert_eq!(result, Err(RegistryError::NotCalledByApplication));
+
+        // Applications of other modules cannot list themselves
+        let other = application("other");
+        app.runtime.set_application_description(other, description(module("other")));
+        app.runtime.set_authenticated_caller_id(other);
+        let result = app
+            .execute_operation(Operation::Register { bot_id: "momentum".to_string() })
+            .blocking_wait();
+        assert_eq!(result, Err(RegistryError::UnauthorizedApplication));
+
+        let momentum = application("momentum");
+        app.runtime.set_application_description(momentum, description(module("bot-state")));
+        app.runtime.set_authenticated_caller_id(momentum);
+        let result = app
+            .execute_operation(Operation::Register { bot_id: String::new() })
+            .blocking_wait();
+        assert_eq!(result, Err(RegistryError::InvalidBotId));
+
+        let result = app
+            .execute_operation(Operation::Register { bot_id: "momentum".to_string() })
+            .blocking_wait();
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_messages_upsert_entries() {
+        let mut app = create_and_instantiate_app();
+        let momentum = application("momentum");
+
+        deliver(&mut app, Message::Register {
+            application_id: momentum,
+            bot_id: "momentum".to_string(),
+            bot_chain: chain("momentum"),
+        });
+        let entry = get_entry(&app, momentum, "momentum").expect("Bot should be registered");
+        assert_eq!(entry.registered_at, NOW_MS);
+        assert_eq!(entry.last_reported_at, 0);
+        assert_eq!(entry.report, MetricsReport::default());
+
+        let report = MetricsReport {
+            one_day: WindowMetrics {
+                directional_accuracy_bps: 6000,
+                rmse_micro: 1_500_000,
+                total_predictions: 10,
+            },
+            all_time: WindowMetrics {
+                directional_accuracy_bps: 5500,
+                rmse_micro: 2_000_000,
+                total_predictions: 40,
+            },
+            follower_count: 3,
+        };
+        app.runtime.set_system_time(Timestamp::from((NOW_MS + 1000) * 1000));
+        deliver(&mut app, Message::ReportMetrics {
+            application_id: momentum,
+            bot_id: "momentum".to_string(),
+            bot_chain: chain("momentum"),
+            report: report.clone(),
+        });
+        let entry = get_entry(&app, momentum, "momentum").expect("Bot should be registered");
+        assert_eq!(entry.registered_at, NOW_MS);
+        assert_eq!(entry.last_reported_at, NOW_MS + 1000);
+        assert_eq!(entry.report, report);
+
+        // Reports from bots that are not registered are ignored
+        let sentiment = application("sentiment");
+        deliver(&mut app, Message::ReportMetrics {
+            application_id: sentiment,
+            bot_id: "sentiment".to_string(),
+            bot_chain: chain("sentiment"),
+            report: report.clone(),
+        });
+        assert_eq!(get_entry(&app, sentiment, "sentiment"), None);
+
+        deliver(&mut app, Message::Unregister {
+            application_id: momentum,
+            bot_id: "momentum".to_string(),
+        });
+        deliver(&mut app, Message::ReportMetrics {
+            application_id: momentum,
+            bot_id: "momentum".to_string(),
+            bot_chain: chain("momentum"),
+            report,
+        });
+        assert_eq!(get_entry(&app, momentum, "momentum"), None);
+    }
+
+    #[test]
+    fn test_bots_of_one_application_are_kept_apart() {
+        let mut app = create_and_instantiate_app();
+        let hosted = application("hosted");
+
+        for bot_id in ["momentum", "sentiment"] {
+            deliver(&mut app, Message::Register {
+                application_id: hosted,
+                bot_id: bot_id.to_string(),
+                bot_chain: chain("hosted"),
+            });
+        }
+        assert!(get_entry(&app, hosted, "momentum").is_some());
+        assert!(get_entry(&app, hosted, "sentiment").is_some());
+
+        deliver(&mut app, Message::Unregister {
+            application_id: hosted,
+            bot_id: "momentum".to_string(),
+        });
+        assert_eq!(get_entry(&app, hosted, "momentum"), None);
+        assert!(get_entry(&app, hosted, "sentiment").is_some());
+    }
+
+    fn deliver(app: &mut BotRegistryContract, message: Message) {
+        app.execute_message(message)
+            .blocking_wait();
+    }
+
+    fn get_entry(
+        app: &BotRegistryContract,
+        application_id: ApplicationId,
+        bot_id: &str,
+    ) -> Option<BotEntry> {
+        let key = BotKey {
+            application_id,
+            bot_id: bot_id.to_string(),
+        };
+        app.state.bots.get(&key)
+            .blocking_wait()
+            .expect("Failed to read registry entry")
+    }
+
+    fn application(name: &str) -> ApplicationId {
+        ApplicationId::new(CryptoHash::test_hash(name))
+    }
+
+    fn chain(name: &str) -> ChainId {
+        ChainId(CryptoHash::test_hash(name))
+    }
+
+    fn module(name: &str) -> ModuleId {
+        let hash = CryptoHash::test_hash(name);
+        ModuleId::new(hash, hash, VmRuntime::Wasm)
+    }
+
+    fn description(module_id: ModuleId) -> ApplicationDescription {
+        ApplicationDescription {
+            module_id,
+            creator_chain_id: chain("bot"),
+            block_height: BlockHeight(0),
+            application_index: 0,
+            parameters: Vec::new(),
+            required_application_ids: Vec::new(),
+        }
+    }
+
+    fn create_and_instantiate_app() -> BotRegistryContract {
+        let runtime = ContractRuntime::new()
+            .with_application_parameters(RegistryParameters {
+                bot_state_module: module("bot-state"),
+            })
+            .with_authenticated_caller_id(None)
+            .with_system_time(Timestamp::from(NOW_MS * 1000))
+            .with_chain_id(chain("registry"))
+            .with_application_creator_chain_id(chain("registry"));
+        let mut contract = BotRegistryContract {
+            state: RegistryState::load(runtime.root_view_storage_context())
+                .blocking_wait()
+                .expect("Failed to read from mock key value store"),
+            runtime,
+        };
+
+        contract
+            .instantiate(())
+            .blocking_wait();
+
+        contract
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Errors returned by registry operations
+#[derive(Debug, Clone, Error, Serialize, Deserialize, PartialEq, Eq)]
+pub enum RegistryError {
+    /// Registry operations must come from a bot application via `call_application`
+    #[error("Operation must be called by a bot application")]
+    NotCalledByApplication,
+
+    /// Only applications of the configured bot module may register and report
+    #[error("Calling application is not a bot application")]
+    UnauthorizedApplication,
+
+    #[error("Bot id must be non-empty and <= 64 characters")]
+    InvalidBotId,
+}
@@ -0,0 +1,88 @@
+use async_graphql::SimpleObject;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{BotEntry, WindowMetrics};
+
+/// Criteria the leaderboard can be sorted by
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, async_graphql::Enum)]
+pub enum RankBy {
+    /// Highest directional accuracy first
+    DirectionalAccuracy,
+    /// Lowest RMSE first
+    Rmse,
+    /// Most followers first
+    Followers,
+}
+
+/// Age (milliseconds) after which a report says nothing about the last 24 hours or the
+/// current followers
+pub const REPORT_TTL_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// Window whose metrics are used for ranking
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, async_graphql::Enum)]
+pub enum RankingWindow {
+    OneDay,
+    AllTime,
+}
+
+/// A bot and its position on the leaderboard
+#[derive(Debug, Clone, PartialEq, SimpleObject)]
+pub struct LeaderboardEntry {
+    /// 1-based position
+    pub rank: u32,
+    pub bot: BotEntry,
+}
+
+impl RankingWindow {
+    fn metrics<'a>(&self, bot: &'a BotEntry) -> &'a WindowMetrics {
+        match self {
+            RankingWindow::OneDay => &bot.report.one_day,
+            RankingWindow::AllTime => &bot.report.all_time,
+        }
+    }
+}
+
+/// Sort bots for the leaderboard at `now` (milliseconds), keeping those with at least
+/// `min_predictions` resolved predictions in `window`
+///
+/// Reports decay: a bot that has not reported for `REPORT_TTL_MS` is ranked (and listed)
+/// with empty 24-hour metrics and no followers, while its all-time metrics are kept.
+///
+/// Bots without any resolved prediction in the window always rank below bots
+/// with some, so an empty track record cannot top the accuracy or RMSE boards.
+/// Ties are broken by more predictions, then by bot id.
+pub fn rank(
+    mut bots: Vec<BotEntry>,
+    rank_by: RankBy,
+    window: RankingWindow,
+    min_predictions: u64,
+    now: u64,
+) -> Vec<LeaderboardEntry> {
+    for bot in &mut bots {
+        if now.saturating_sub(bot.last_reported_at) > REPORT_TTL_MS {
+            bot.report.one_day = WindowMetrics::default();
+            bot.report.follower_count = 0;
+        }
+    }
+    bots.retain(|bot| window.metrics(bot).total_predictions >= min_predictions);
+    bots.sort_by(|a, b| {
+        let (ma, mb) = (window.metrics(a), window.metrics(b));
+        let has_track_record = (mb.total_predictions > 0).cmp(&(ma.total_predictions > 0));
+        let primary = match rank_by {
+            RankBy::DirectionalAccuracy => has_track_record
+                .then(mb.directional_accuracy_bps.cmp(&ma.directional_accuracy_bps)),
+            RankBy::Rmse => has_track_record.then(ma.rmse_micro.cmp(&mb.rmse_micro)),
+            RankBy::Followers => b.report.follower_count.cmp(&a.report.follower_count),
+        };
+        primary
+            .then(mb.total_predictions.cmp(&ma.total_predictions))
+            .then_with(|| a.bot_id.cmp(&b.bot_id))
+    });
+    bots.into_iter()
+        .enumerate()
+        .map(|(i, bot)| LeaderboardEntry {
+            rank: i as u32 + 1,
+            bot,
+        })
+        .collect()
+}
@@ -0,0 +1,29 @@
+mod error;
+mod leaderboard;
+mod message;
+mod operation;
+mod parameters;
+mod state;
+
+pub use error::RegistryError;
+pub use leaderboard::{rank, LeaderboardEntry, RankBy, RankingWindow, REPORT_TTL_MS};
+pub use message::Message;
+pub use operation::Operation;
+pub use parameters::RegistryParameters;
+pub use state::{BotEntry, BotKey, MetricsReport, RegistryState, WindowMetrics};
+
+use async_graphql::{Request, Response};
+use linera_sdk::linera_base_types::{ContractAbi, ServiceAbi};
+
+/// ABI for the BotRegistry application
+pub struct RegistryAbi;
+
+impl ContractAbi for RegistryAbi {
+    type Operation = Operation;
+    type Response = Result<(), RegistryError>;
+}
+
+impl ServiceAbi for RegistryAbi {
+    type Query = Request;
+    type QueryResponse = Response;
+}
@@ -0,0 +1,29 @@
+use linera_sdk::linera_base_types::{ApplicationId, ChainId};
+use serde::{Deserialize, Serialize};
+
+use crate::state::MetricsReport;
+
+/// Messages forwarded from a bot's chain to the registry chain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Message {
+    /// A bot application registered one of its bots
+    Register {
+        application_id: ApplicationId,
+        bot_id: String,
+        bot_chain: ChainId,
+    },
+
+    /// A bot application published new metrics for one of its bots
+    ReportMetrics {
+        application_id: ApplicationId,
+        bot_id: String,
+        bot_chain: ChainId,
+        report: MetricsReport,
+    },
+
+    /// A bot application retired one of its bots
+    Unregister {
+        application_id: ApplicationId,
+        bot_id: String,
+    },
+}
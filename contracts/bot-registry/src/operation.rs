@@ -0,0 +1,20 @@
+use linera_sdk::graphql::GraphQLMutationRoot;
+use serde::{Deserialize, Serialize};
+
+use crate::state::MetricsReport;
+
+/// Operations called by bot applications (through `call_application`) on their own chain
+#[derive(Debug, Serialize, Deserialize, GraphQLMutationRoot)]
+pub enum Operation {
+    /// Register a bot hosted by the calling application in the registry
+    Register { bot_id: String },
+
+    /// Publish the latest metrics of a bot hosted by the calling application
+    ReportMetrics {
+        bot_id: String,
+        report: MetricsReport,
+    },
+
+    /// Remove a bot hosted by the calling application from the registry
+    Unregister { bot_id: String },
+}
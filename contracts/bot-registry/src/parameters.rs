@@ -0,0 +1,10 @@
+use linera_sdk::linera_base_types::ModuleId;
+use serde::{Deserialize, Serialize};
+
+/// Deployment settings of the registry
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RegistryParameters {
+    /// Module of the bot applications allowed to register and report, as printed by
+    /// `linera publish-module`
+    pub bot_state_module: ModuleId,
+}
@@ -0,0 +1,115 @@
+#![cfg_attr(target_arch = "wasm32", no_main)]
+
+use std::sync::Arc;
+
+use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+use linera_sdk::{
+    linera_base_types::{ApplicationId, WithServiceAbi},
+    views::View,
+    Service,
+    ServiceRuntime,
+};
+
+use bot_registry::{
+    rank, BotEntry, BotKey, LeaderboardEntry, RankBy, RankingWindow, RegistryParameters,
+    RegistryState,
+};
+
+/// Default number of entries returned by the leaderboard
+const DEFAULT_LEADERBOARD_SIZE: usize = 20;
+
+/// Maximum number of entries returned by the leaderboard
+const MAX_LEADERBOARD_SIZE: usize = 100;
+
+pub struct BotRegistryService {
+    state: Arc<RegistryState>,
+    runtime: Arc<ServiceRuntime<Self>>,
+}
+
+linera_sdk::service!(BotRegistryService);
+
+impl WithServiceAbi for BotRegistryService {
+    type Abi = bot_registry::RegistryAbi;
+}
+
+impl Service for BotRegistryService {
+    type Parameters = RegistryParameters;
+
+    async fn new(runtime: ServiceRuntime<Self>) -> Self {
+        let state = RegistryState::load(runtime.root_view_storage_context())
+            .await
+            .expect("Failed to load state");
+        BotRegistryService {
+            state: Arc::new(state),
+            runtime: Arc::new(runtime),
+        }
+    }
+
+    async fn handle_query(&self, query: Self::Query) -> Self::QueryResponse {
+        Schema::build(
+            RegistryQueryRoot {
+                state: self.state.clone(),
+                runtime: self.runtime.clone(),
+            },
+            EmptyMutation,
+            EmptySubscription,
+        )
+        .finish()
+        .execute(query)
+        .await
+    }
+}
+
+/// GraphQL query root
+struct RegistryQueryRoot {
+    state: Arc<RegistryState>,
+    runtime: Arc<ServiceRuntime<BotRegistryService>>,
+}
+
+#[Object]
+impl RegistryQueryRoot {
+    /// Get every registered bot
+    async fn bots(&self) -> async_graphql::Result<Vec<BotEntry>> {
+        Ok(self.state.all_bots().await?)
+    }
+
+    /// Get a registered bot by its application id and bot id
+    async fn bot(
+        &self,
+        application_id: ApplicationId,
+        bot_id: String,
+    ) -> async_graphql::Result<Option<BotEntry>> {
+        Ok(self.state.bots.get(&BotKey { application_id, bot_id }).await?)
+    }
+
+    /// Rank registered bots (defaults: directional accuracy over the last 24 hours)
+    ///
+    /// `min_predictions` hides bots with too short a track record in the window and
+    /// `bot_id_prefix` filters by bot id. Bots that stopped reporting lose their 24-hour
+    /// metrics and followers after a day.
+    async fn leaderboard(
+        &self,
+        rank_by: Option<RankBy>,
+        window: Option<RankingWindow>,
+        min_predictions: Option<u64>,
+        bot_id_prefix: Option<String>,
+        limit: Option<usize>,
+    ) -> async_graphql::Result<Vec<LeaderboardEntry>> {
+        let mut bots = self.state.all_bots().await?;
+        if let Some(prefix) = bot_id_prefix {
+            bots.retain(|bot| bot.bot_id.starts_with(&prefix));
+        }
+        let limit = limit.unwrap_or(DEFAULT_LEADERBOARD_SIZE).min(MAX_LEADERBOARD_SIZE);
+        let now = self.runtime.system_time().micros() / 1000; // Convert to ms
+
+        let mut ranked = rank(
+            bots,
+            rank_by.unwrap_or(RankBy::DirectionalAccuracy),
+            window.unwrap_or(RankingWindow::OneDay),
+            min_predictions.unwrap_or(0),
+            now,
+        );
+        ranked.truncate(limit);
+        Ok(ranked)
+    }
+}
@@ -0,0 +1,84 @@
+use async_graphql::{InputObject, SimpleObject};
+use linera_sdk::linera_base_types::{ApplicationId, ChainId};
+use linera_sdk::views::{linera_views, MapView, RootView, ViewError, ViewStorageContext};
+use serde::{Deserialize, Serialize};
+
+/// The application state for the bot registry (kept on the registry chain)
+#[derive(RootView, SimpleObject)]
+#[view(context = ViewStorageContext)]
+pub struct RegistryState {
+    /// Registered bots, keyed by their bot-state application and bot id
+    pub bots: MapView<BotKey, BotEntry>,
+}
+
+/// Identifies a bot: one bot-state application can host several bots
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, SimpleObject, InputObject)]
+#[graphql(input_name = "BotKeyInput")]
+pub struct BotKey {
+    pub application_id: ApplicationId,
+    pub bot_id: String,
+}
+
+/// A registered bot and its latest reported metrics
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, SimpleObject)]
+pub struct BotEntry {
+    /// The bot-state application publishing this bot's signals
+    pub application_id: ApplicationId,
+
+    /// Bot identifier, unique within its bot-state application
+    pub bot_id: String,
+
+    /// Chain the bot-state application runs on
+    pub chain_id: ChainId,
+
+    /// Unix timestamp (milliseconds) when the bot registered
+    pub registered_at: u64,
+
+    /// Unix timestamp (milliseconds) of the latest metrics report (0 if none)
+    pub last_reported_at: u64,
+
+    /// Latest metrics reported by the bot
+    pub report: MetricsReport,
+}
+
+/// Metrics a bot reports to the registry
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, SimpleObject, InputObject)]
+#[graphql(input_name = "MetricsReportInput")]
+pub struct MetricsReport {
+    /// Metrics over the last 24 hours
+    pub one_day: WindowMetrics,
+
+    /// Metrics since the bot was created
+    pub all_time: WindowMetrics,
+
+    /// Number of accounts following the bot
+    pub follower_count: u64,
+}
+
+/// Accuracy figures for one time window
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, SimpleObject, InputObject)]
+#[graphql(input_name = "WindowMetricsInput")]
+pub struct WindowMetrics {
+    /// Directional accuracy in basis points (0-10000, where 10000 = 100%)
+    pub directional_accuracy_bps: u64,
+
+    /// Root Mean Square Error in micro-USD
+    pub rmse_micro: u64,
+
+    /// Number of resolved predictions in the window
+    pub total_predictions: u64,
+}
+
+impl RegistryState {
+    /// Get every registered bot
+    pub async fn all_bots(&self) -> Result<Vec<BotEntry>, ViewError> {
+        let mut bots = Vec::new();
+        self.bots
+            .for_each_index_value(|_, entry| {
+                bots.push(entry.into_owned());
+                Ok(())
+            })
+            .await?;
+        Ok(bots)
+    }
+}
@@ -0,0 +1,155 @@
+use bot_registry::{
+    rank, BotEntry, MetricsReport, RankBy, RankingWindow, WindowMetrics, REPORT_TTL_MS,
+};
+use linera_sdk::linera_base_types::{ApplicationId, ChainId, CryptoHash};
+
+fn bot(
+    bot_id: &str,
+    accuracy_bps: u64,
+    rmse_micro: u64,
+    predictions: u64,
+    followers: u64,
+) -> BotEntry {
+    let metrics = WindowMetrics {
+        directional_accuracy_bps: accuracy_bps,
+        rmse_micro,
+        total_predictions: predictions,
+    };
+    BotEntry {
+        application_id: ApplicationId::new(CryptoHash::test_hash(bot_id)),
+        bot_id: bot_id.to_string(),
+        chain_id: ChainId(CryptoHash::test_hash(bot_id)),
+        registered_at: 1000,
+        last_reported_at: 2000,
+        report: MetricsReport {
+            one_day: metrics.clone(),
+            all_time: metrics,
+            follower_count: followers,
+        },
+    }
+}
+
+/// Ranking time, right after the reports of `bot`
+const NOW_MS: u64 = 2000;
+
+fn ranked_ids(bots: Vec<BotEntry>, rank_by: RankBy, min_predictions: u64) -> Vec<String> {
+    rank(bots, rank_by, RankingWindow::OneDay, min_predictions, NOW_MS)
+        .into_iter()
+        .map(|entry| entry.bot.bot_id)
+        .collect()
+}
+
+#[test]
+fn test_rank_by_directional_accuracy() {
+    let bots = vec![
+        bot("a", 5000, 100, 10, 0),
+        bot("b", 7000, 300, 10, 0),
+        bot("c", 7000, 200, 20, 0),
+        bot("new", 0, 0, 0, 5),
+    ];
+
+    let ranked = rank(bots, RankBy::DirectionalAccuracy, RankingWindow::OneDay, 0, NOW_MS);
+    let ids: Vec<_> = ranked.iter().map(|entry| entry.bot.bot_id.as_str()).collect();
+    // Ties are broken by the number of predictions; bots without predictions come last
+    assert_eq!(ids, ["c", "b", "a", "new"]);
+    assert_eq!(ranked.iter().map(|entry| entry.rank).collect::<Vec<_>>(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn test_rank_by_rmse_puts_empty_track_record_last() {
+    let bots = vec![
+        bot("new", 0, 0, 0, 0),
+        bot("a", 5000, 300, 10, 0),
+        bot("b", 5000, 100, 10, 0),
+    ];
+
+    assert_eq!(ranked_ids(bots, RankBy::Rmse, 0), ["b", "a", "new"]);
+}
+
+#[test]
+fn test_rank_by_followers() {
+    let bots = vec![
+        bot("a", 9000, 100, 10, 1),
+        bot("b", 1000, 900, 10, 8),
+        bot("c", 5000, 500, 10, 8),
+    ];
+
+    // Equal follower counts fall back to predictions, then bot id
+    assert_eq!(ranked_ids(bots, RankBy::Followers, 0), ["b", "c", "a"]);
+}
+
+#[test]
+fn test_rank_filters_by_min_predictions() {
+    let bots = vec![
+        bot("a", 9000, 100, 3, 0),
+        bot("b", 6000, 200, 30, 0),
+    ];
+
+    assert_eq!(ranked_ids(bots, RankBy::DirectionalAccuracy, 10), ["b"]);
+}
+
+#[test]
+fn test_rank_uses_selected_window() {
+    let mut veteran = bot("veteran", 5000, 100, 100, 0);
+    veteran.report.one_day = WindowMetrics::default();
+    let fresh = bot("fresh", 6000, 100, 5, 0);
+
+    let one_day = rank(
+        vec![veteran.clone(), fresh.clone()],
+        RankBy::DirectionalAccuracy,
+        RankingWindow::OneDay,
+        1,
+        NOW_MS,
+    );
+    assert_eq!(one_day.len(), 1);
+    assert_eq!(one_day[0].bot.bot_id, "fresh");
+
+    let all_time = rank(
+        vec![veteran, fresh],
+        RankBy::DirectionalAccuracy,
+        RankingWindow::AllTime,
+        1,
+        NOW_MS,
+    );
+    assert_eq!(all_time.len(), 2);
+    assert_eq!(all_time[0].bot.bot_id, "fresh");
+}
+
+#[test]
+fn test_stale_reports_decay() {
+    let mut stale = bot("stale", 9000, 100, 10, 50);
+    stale.last_reported_at = 0;
+    let active = bot("active", 6000, 200, 10, 5);
+    let now = REPORT_TTL_MS + 1;
+
+    // A day-old report no longer counts towards the 24-hour window or followers
+    let one_day = rank(
+        vec![stale.clone(), active.clone()],
+        RankBy::DirectionalAccuracy,
+        RankingWindow::OneDay,
+        1,
+        now,
+    );
+    assert_eq!(one_day.len(), 1);
+    assert_eq!(one_day[0].bot.bot_id, "active");
+
+    let followers = rank(
+        vec![stale.clone(), active.clone()],
+        RankBy::Followers,
+        RankingWindow::AllTime,
+        0,
+        now,
+    );
+    assert_eq!(followers[0].bot.bot_id, "active");
+    assert_eq!(followers[1].bot.report.follower_count, 0);
+
+    // All-time metrics do not expire
+    let all_time = rank(
+        vec![stale, active],
+        RankBy::DirectionalAccuracy,
+        RankingWindow::AllTime,
+        1,
+        now,
+    );
+    assert_eq!(all_time[0].bot.bot_id, "stale");
+}
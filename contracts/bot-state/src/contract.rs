@@ -1,8 +1,15 @@
 #![cfg_attr(target_arch = "wasm32", no_main)]
 
-use bot_state::{BotState, Operation};
+use bot_state::{
+    AccuracyMetrics, Bot, BotEvent, BotParameters, BotResponse, BotState, BotStateError, Commitment,
+    CommittedPrediction, Follower, InstantiationArgument, Message, Operation, Signal, Slash,
+    SlashReason, SlashingPolicy, SubscriptionTier, BOT_STREAM_NAME, MAX_TIMESTAMP_DRIFT_MS,
+};
+use bot_registry::RegistryAbi;
 use linera_sdk::{
-    linera_base_types::WithContractAbi,
+    linera_base_types::{
+        Account, AccountOwner, Amount, ChainId, StreamName, StreamUpdate, WithContractAbi,
+    },
     views::{RootView, View},
     Contract, ContractRuntime,
 };
@@ -19,10 +26,10 @@ impl WithContractAbi for BotStateContract {
 }
 
 impl Contract for BotStateContract {
-    type Message = ();
-    type InstantiationArgument = String; // bot_id
-    type Parameters = ();
-    type EventValue = ();
+    type Message = Message;
+    type InstantiationArgument = InstantiationArgument;
+    type Parameters = BotParameters;
+    type EventValue = BotEvent;
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
         let state = BotState::load(runtime.root_view_storage_context())
@@ -31,110 +38,945 @@ impl Contract for BotStateContract {
         BotStateContract { state, runtime }
     }
 
-    async fn instantiate(&mut self, bot_id: String) {
+    async fn instantiate(&mut self, argument: InstantiationArgument) {
         // Validate that the application parameters were configured correctly
-        self.runtime.application_parameters();
+        self.runtime.application_parameters().validate()
+            .expect("Invalid application parameters");
 
-        self.state.bot_id.set(bot_id);
-        self.state.follower_count.set(0);
-        self.state.accuracy_24h.set(Default::default());
+        self.state.admin.set(self.runtime.authenticated_signer());
+        self.state.registry.set(argument.registry);
+        self.create_bot(
+            argument.bot_id,
+            argument.operator,
+            argument.resolver,
+            argument.stake,
+            argument.slashing_policy,
+        )
+        .await
+        .expect("Invalid first bot");
     }
 
     async fn execute_operation(&mut self, operation: Operation) -> Self::Response {
         match operation {
+            Operation::CreateBot {
+                bot_id,
+                operator,
+                resolver,
+                stake,
+                slashing_policy,
+            } => {
+                self.check_signer("admin", *self.state.admin.get())?;
+                self.create_bot(bot_id, operator, resolver, stake, slashing_policy).await?;
+            }
+
+            Operation::RetireBot { bot_id } => {
+                let bot = load_active_bot(&mut self.state, &bot_id).await?;
+                let operator = *bot.operator.get();
+                let admin = *self.state.admin.get();
+                if self.check_signer("operator", operator).is_err() {
+                    self.check_signer("admin", admin)?;
+                }
+
+                // Unregister first: an error does not undo changes already made
+                self.call_registry(bot_registry::Operation::Unregister { bot_id: bot_id.clone() })?;
+                // The stake stays held: outstanding predictions may still be slashed
+                self.bot(&bot_id).await.retired.set(true);
+            }
+
+            Operation::ReleaseStake { bot_id } => {
+                let bot = load_bot(&mut self.state, &bot_id).await?;
+                if !*bot.retired.get() {
+                    return Err(BotStateError::BotNotRetired(bot_id));
+                }
+                let operator = *bot.operator.get();
+                let admin = *self.state.admin.get();
+                if self.check_signer("operator", operator).is_err() {
+                    self.check_signer("admin", admin)?;
+                }
+
+                // Commitments that were never revealed are scored, and possibly slashed, first
+                let current_time = self.runtime.system_time().micros() / 1000; // Convert to ms
+                self.enforce_reveals(&bot_id, current_time).await;
+                let grace_ms = self.runtime.application_parameters().resolution_grace_ms;
+                let outstanding = self.bot(&bot_id).await
+                    .outstanding_predictions(current_time, grace_ms).await
+                    .expect("Failed to read outstanding predictions");
+                if outstanding > 0 {
+                    return Err(BotStateError::StakeLocked(outstanding));
+                }
+
+                let bot = self.bot(&bot_id).await;
+                let stake = *bot.stake.get();
+                bot.stake.set(Amount::ZERO);
+                if let Some(operator) = operator {
+                    if stake > Amount::ZERO {
+                        let application_owner = self.runtime.application_id().into();
+                        let destination = Account {
+                            chain_id: self.runtime.chain_id(),
+                            owner: operator,
+                        };
+                        self.runtime.transfer(application_owner, destination, stake);
+                    }
+                }
+            }
+
             Operation::SubmitPrediction {
+                bot_id,
+                asset,
                 timestamp,
+                horizon_ms,
                 action,
+                reference_price_micro,
                 predicted_price_micro,
                 confidence_bps,
                 reasoning,
             } => {
+                self.check_operator(&bot_id).await?;
+                load_active_bot(&mut self.state, &bot_id).await?;
+                self.check_asset(&asset)?;
+
                 // Parse string values to u64
-                let timestamp_u64 = timestamp.parse::<u64>()
-                    .expect("Invalid timestamp format");
-                let price_micro_u64 = predicted_price_micro.parse::<u64>()
-                    .expect("Invalid price format");
+                let timestamp_u64 = BotStateError::parse_u64("timestamp", &timestamp)?;
+                let horizon_ms_u64 = BotStateError::parse_u64("horizon", &horizon_ms)?;
+                let reference_price_u64 =
+                    BotStateError::parse_u64("reference price", &reference_price_micro)?;
+                let price_micro_u64 =
+                    BotStateError::parse_u64("predicted price", &predicted_price_micro)?;
+
+                // Timestamps start the signal schedule, so they cannot run ahead of the chain
+                let current_time = self.runtime.system_time().micros() / 1000; // Convert to ms
+                if timestamp_u64 > current_time.saturating_add(MAX_TIMESTAMP_DRIFT_MS) {
+                    return Err(BotStateError::TimestampInFuture {
+                        timestamp: timestamp_u64,
+                        now: current_time,
+                        max_drift_ms: MAX_TIMESTAMP_DRIFT_MS,
+                    });
+                }
 
                 // Create signal from parameters
-                let signal = bot_state::Signal {
+                let signal = Signal {
+                    id: 0, // Assigned when the signal is stored
                     timestamp: timestamp_u64,
+                    target_timestamp: timestamp_u64.saturating_add(horizon_ms_u64),
+                    asset,
                     action,
+                    reference_price_micro: reference_price_u64,
                     predicted_price_micro: price_micro_u64,
                     confidence_bps,
                     reasoning,
                     actual_price_micro: None,
+                    commitment: None,
                 };
 
                 // Validate signal
-                if let Err(e) = signal.validate() {
-                    panic!("Invalid signal: {}", e);
+                signal.validate()?;
+
+                // Check timestamp is monotonically increasing for the asset
+                let latest = self.bot(&bot_id).await.latest_signal_for(&signal.asset).await
+                    .expect("Failed to read latest signal");
+                if let Some(latest) = latest {
+                    if signal.timestamp == latest.timestamp {
+                        return Err(BotStateError::DuplicateTimestamp(signal.timestamp));
+                    }
+                    if signal.timestamp < latest.timestamp {
+                        return Err(BotStateError::TimestampNotIncreasing {
+                            timestamp: signal.timestamp,
+                            latest: latest.timestamp,
+                        });
+                    }
                 }
 
-                // Check timestamp is monotonically increasing
-                if let Some(latest) = self.state.latest_signal.get() {
-                    if signal.timestamp <= latest.timestamp {
-                        panic!("Signal timestamp must be greater than previous signal");
+                // A late signal still pays for the deadline it missed
+                self.enforce_schedule(&bot_id, current_time).await;
+                self.enforce_reveals(&bot_id, current_time).await;
+
+                self.bot(&bot_id).await.last_signal_at.set(current_time);
+                self.publish_signal(&bot_id, signal).await;
+            }
+
+            Operation::CommitPrediction {
+                bot_id,
+                asset,
+                commitment,
+                horizon_ms,
+            } => {
+                self.check_operator(&bot_id).await?;
+                load_active_bot(&mut self.state, &bot_id).await?;
+                self.check_asset(&asset)?;
+                let horizon_ms_u64 = BotStateError::parse_u64("horizon", &horizon_ms)?;
+                if horizon_ms_u64 == 0 {
+                    return Err(BotStateError::TargetNotAfterTimestamp);
+                }
+
+                // Commitment times become signal timestamps, so they must be unique per asset
+                let current_time = self.runtime.system_time().micros() / 1000; // Convert to ms
+                let latest = self.bot(&bot_id).await.latest_commitment_for(&asset).await
+                    .expect("Failed to read latest commitment");
+                if let Some(latest) = latest {
+                    if current_time <= latest.committed_at {
+                        return Err(BotStateError::DuplicateTimestamp(current_time));
                     }
                 }
 
-                // Update state
-                self.state.latest_signal.set(Some(signal));
+                self.enforce_schedule(&bot_id, current_time).await;
+                self.enforce_reveals(&bot_id, current_time).await;
+
+                let bot = self.bot(&bot_id).await;
+                let id = *bot.next_commitment_id.get();
+                let commitment = Commitment {
+                    id,
+                    asset,
+                    hash: commitment,
+                    committed_at: current_time,
+                    target_timestamp: current_time.saturating_add(horizon_ms_u64),
+                    signal_id: None,
+                };
+                bot.commitments.insert(&id, commitment.clone())
+                    .expect("Failed to store commitment");
+                bot.latest_commitment_ids.insert(&commitment.asset, id)
+                    .expect("Failed to store commitment");
+                bot.open_commitments.insert(&id)
+                    .expect("Failed to store commitment");
+                bot.next_commitment_id.set(id + 1);
+                bot.last_signal_at.set(current_time);
+                self.runtime.emit(
+                    StreamName::from(BOT_STREAM_NAME),
+                    &BotEvent::PredictionCommitted { bot_id, commitment },
+                );
+            }
+
+            Operation::RevealPrediction {
+                bot_id,
+                commitment_id,
+                action,
+                reference_price_micro,
+                predicted_price_micro,
+                confidence_bps,
+                salt,
+                reasoning,
+            } => {
+                self.check_operator(&bot_id).await?;
+                let commitment_id = BotStateError::parse_u64("commitment id", &commitment_id)?;
+                let reference_price_u64 =
+                    BotStateError::parse_u64("reference price", &reference_price_micro)?;
+                let price_micro_u64 =
+                    BotStateError::parse_u64("predicted price", &predicted_price_micro)?;
+
+                let bot = load_active_bot(&mut self.state, &bot_id).await?;
+                let mut commitment = bot.commitments.get(&commitment_id).await
+                    .expect("Failed to read commitment")
+                    .ok_or(BotStateError::UnknownCommitment(commitment_id))?;
+                if commitment.signal_id.is_some() {
+                    return Err(BotStateError::AlreadyRevealed(commitment_id));
+                }
+                let current_time = self.runtime.system_time().micros() / 1000; // Convert to ms
+                if current_time > commitment.target_timestamp {
+                    return Err(BotStateError::RevealTooLate {
+                        id: commitment_id,
+                        target_timestamp: commitment.target_timestamp,
+                    });
+                }
+
+                let prediction = CommittedPrediction {
+                    bot_id: bot_id.clone(),
+                    chain_id: self.runtime.chain_id(),
+                    asset: commitment.asset.clone(),
+                    action,
+                    reference_price_micro: reference_price_u64,
+                    predicted_price_micro: price_micro_u64,
+                    confidence_bps,
+                    reasoning: reasoning.clone(),
+                    salt,
+                };
+                if prediction.hash() != commitment.hash {
+                    return Err(BotStateError::CommitmentMismatch(commitment_id));
+                }
+
+                let signal = Signal {
+                    id: 0, // Assigned when the signal is stored
+                    timestamp: commitment.committed_at,
+                    target_timestamp: commitment.target_timestamp,
+                    asset: commitment.asset.clone(),
+                    action,
+                    reference_price_micro: reference_price_u64,
+                    predicted_price_micro: price_micro_u64,
+                    confidence_bps,
+                    reasoning,
+                    actual_price_micro: None,
+                    commitment: Some(commitment.hash),
+                };
+                signal.validate()?;
+                let timestamp_taken = bot.signal_id_at(&signal.asset, signal.timestamp).await
+                    .expect("Failed to read signal index");
+                if timestamp_taken.is_some() {
+                    return Err(BotStateError::DuplicateTimestamp(signal.timestamp));
+                }
+
+                commitment.signal_id = Some(self.publish_signal(&bot_id, signal).await);
+                let bot = self.bot(&bot_id).await;
+                bot.commitments.insert(&commitment_id, commitment)
+                    .expect("Failed to store commitment");
+                bot.open_commitments.remove(&commitment_id)
+                    .expect("Failed to store commitment");
             }
 
             Operation::ResolveSignal {
+                bot_id,
+                asset,
                 timestamp,
                 actual_price_micro,
             } => {
+                self.check_resolver(&bot_id).await?;
+
                 // Parse string values to u64
-                let timestamp_u64 = timestamp.parse::<u64>()
-                    .expect("Invalid timestamp format");
-                let actual_price_u64 = actual_price_micro.parse::<u64>()
-                    .expect("Invalid actual price format");
-
-                if let Some(signal) = self.state.latest_signal.get().clone() {
-                    if signal.timestamp == timestamp_u64 {
-                        let previous_price_micro = signal.predicted_price_micro; // Simplified
-                        let mut resolved_signal = signal.clone();
-                        resolved_signal.actual_price_micro = Some(actual_price_u64);
-
-                        // Update accuracy metrics
-                        let mut metrics = self.state.accuracy_24h.get().clone();
-                        let current_time = self.runtime.system_time().micros() / 1000; // Convert to ms
-                        metrics.update(&resolved_signal, previous_price_micro, current_time);
-                        self.state.accuracy_24h.set(metrics);
-
-                        self.state.latest_signal.set(Some(resolved_signal));
-                    }
+                let timestamp_u64 = BotStateError::parse_u64("timestamp", &timestamp)?;
+                let actual_price_u64 =
+                    BotStateError::parse_u64("actual price", &actual_price_micro)?;
+
+                // Retired bots still resolve the signals they published
+                let parameters = self.runtime.application_parameters();
+                let bot = load_bot(&mut self.state, &bot_id).await?;
+                let signal_id = bot.signal_id_at(&asset, timestamp_u64).await
+                    .expect("Failed to read signal index")
+                    .ok_or(BotStateError::UnknownSignal(timestamp_u64))?;
+                let is_pending = bot.pending_signals.contains(&signal_id).await
+                    .expect("Failed to read pending signals");
+                if !is_pending {
+                    return Err(BotStateError::AlreadyResolved(signal_id));
+                }
+                let signal = bot.signals.get(&signal_id).await
+                    .expect("Failed to read signal")
+                    .expect("Indexed signal must exist");
+
+                let current_time = self.runtime.system_time().micros() / 1000; // Convert to ms
+                if !signal.is_resolvable_at(current_time, parameters.resolution_grace_ms) {
+                    return Err(BotStateError::OutsideResolutionWindow {
+                        id: signal_id,
+                        target_timestamp: signal.target_timestamp,
+                        grace_ms: parameters.resolution_grace_ms,
+                    });
                 }
+
+                let mut resolved_signal = signal;
+                resolved_signal.actual_price_micro = Some(actual_price_u64);
+
+                bot.pending_signals.remove(&signal_id)
+                    .expect("Failed to update pending signals");
+                bot.signals.insert(&signal_id, resolved_signal.clone())
+                    .expect("Failed to store resolved signal");
+                self.runtime.emit(
+                    StreamName::from(BOT_STREAM_NAME),
+                    &BotEvent::SignalResolved {
+                        bot_id: bot_id.clone(),
+                        signal: resolved_signal.clone(),
+                    },
+                );
+
+                self.enforce_reveals(&bot_id, current_time).await;
+                // Only signals proven to predate the outcome count towards accuracy
+                if resolved_signal.commitment.is_some() {
+                    let metrics = self.bot(&bot_id).await
+                        .record_resolution(&resolved_signal, current_time, parameters.hold_band_bps)
+                        .await
+                        .expect("Failed to update accuracy metrics");
+                    self.publish_metrics(&bot_id, &metrics, current_time).await;
+                }
+                let message = Message::SignalResolved {
+                    bot_id: bot_id.clone(),
+                    signal: resolved_signal,
+                };
+                self.broadcast(&bot_id, message).await;
+            }
+
+            Operation::TransferOperator {
+                bot_id,
+                new_operator,
+            } => {
+                self.check_operator(&bot_id).await?;
+                self.bot(&bot_id).await.operator.set(Some(new_operator));
+            }
+
+            Operation::SetResolver {
+                bot_id,
+                new_resolver,
+            } => {
+                self.check_operator(&bot_id).await?;
+                self.bot(&bot_id).await.resolver.set(Some(new_resolver));
+            }
+
+            Operation::Subscribe { bot_id } => {
+                self.send_to_bot_chain(Message::Subscribe { bot_id })?
+            }
+
+            Operation::Unsubscribe { bot_id } => {
+                self.send_to_bot_chain(Message::Unsubscribe { bot_id })?
+            }
+
+            Operation::SubscribeToEvents => {
+                let bot_chain = self.follower_side_bot_chain()?;
+                let application_id = self.runtime.application_id().forget_abi();
+                self.runtime.subscribe_to_events(
+                    bot_chain,
+                    application_id,
+                    StreamName::from(BOT_STREAM_NAME),
+                );
+            }
+
+            Operation::UnsubscribeFromEvents => {
+                let bot_chain = self.follower_side_bot_chain()?;
+                let application_id = self.runtime.application_id().forget_abi();
+                self.runtime.unsubscribe_from_events(
+                    bot_chain,
+                    application_id,
+                    StreamName::from(BOT_STREAM_NAME),
+                );
+            }
+
+            Operation::AddFollower {
+                bot_id,
+                tier,
+                periods,
+            } => {
+                let follower = self.runtime.authenticated_signer()
+                    .ok_or(BotStateError::MissingSigner)?;
+                if periods == 0 {
+                    return Err(BotStateError::ZeroPeriods);
+                }
+                let bot = load_active_bot(&mut self.state, &bot_id).await?;
+                let tier = bot.subscription_tiers.get(&tier).await
+                    .expect("Failed to read subscription tiers")
+                    .ok_or(BotStateError::UnknownTier(tier))?;
+
+                let current_time = self.runtime.system_time().micros() / 1000; // Convert to ms
+                let active = bot.active_follower(&follower, current_time).await
+                    .expect("Failed to read followers");
+                let is_new_follower = active.is_none();
+
+                let price = tier.price_for(periods);
+                if price > Amount::ZERO {
+                    let application_account = self.application_account();
+                    self.runtime.transfer(follower, application_account, price);
+                    let bot = self.bot(&bot_id).await;
+                    let revenue = bot.revenue.get().saturating_add(price);
+                    bot.revenue.set(revenue);
+                }
+
+                // Renewals extend the running subscription instead of restarting it
+                let (followed_at, starts_at) = match active {
+                    Some(active) => (active.followed_at, active.expires_at),
+                    None => (current_time, current_time),
+                };
+                let duration = tier.period_ms.saturating_mul(periods);
+                let bot = self.bot(&bot_id).await;
+                bot.insert_follower(Follower {
+                    owner: follower,
+                    followed_at,
+                    tier: tier.name,
+                    expires_at: starts_at.saturating_add(duration),
+                })
+                .await
+                .expect("Failed to store follower");
+                bot.prune_expired_followers(current_time).await
+                    .expect("Failed to prune expired followers");
+                if is_new_follower {
+                    self.report_to_registry(&bot_id).await;
+                }
+            }
+
+            Operation::RemoveFollower { bot_id } => {
+                let follower = self.runtime.authenticated_signer()
+                    .ok_or(BotStateError::MissingSigner)?;
+                let bot = load_bot(&mut self.state, &bot_id).await?;
+                let was_following = bot.remove_follower(&follower).await
+                    .expect("Failed to remove follower");
+                if was_following {
+                    self.report_to_registry(&bot_id).await;
+                }
+            }
+
+            Operation::SetSubscriptionTier {
+                bot_id,
+                name,
+                price,
+                period_ms,
+            } => {
+                self.check_operator(&bot_id).await?;
+                let tier = SubscriptionTier {
+                    name,
+                    price,
+                    period_ms: BotStateError::parse_u64("period", &period_ms)?,
+                };
+                tier.validate()?;
+                self.bot(&bot_id).await.subscription_tiers.insert(&tier.name.clone(), tier)
+                    .expect("Failed to store subscription tier");
+            }
+
+            Operation::RemoveSubscriptionTier { bot_id, name } => {
+                self.check_operator(&bot_id).await?;
+                self.bot(&bot_id).await.subscription_tiers.remove(&name)
+                    .expect("Failed to remove subscription tier");
+            }
+
+            Operation::WithdrawRevenue {
+                bot_id,
+                amount,
+                recipient,
+            } => {
+                self.check_operator(&bot_id).await?;
+                let bot = self.bot(&bot_id).await;
+                let available = *bot.revenue.get();
+                if amount > available {
+                    return Err(BotStateError::InsufficientRevenue {
+                        requested: amount,
+                        available,
+                    });
+                }
+                let application_owner = self.runtime.application_id().into();
+                self.runtime.transfer(application_owner, recipient, amount);
+                self.bot(&bot_id).await.revenue.set(available.saturating_sub(amount));
+            }
+
+            Operation::Stake { bot_id, amount } => {
+                self.check_operator(&bot_id).await?;
+                load_active_bot(&mut self.state, &bot_id).await?;
+                if amount == Amount::ZERO {
+                    return Err(BotStateError::ZeroStake);
+                }
+                let operator = self.runtime.authenticated_signer()
+                    .ok_or(BotStateError::MissingSigner)?;
+                self.deposit_stake(&bot_id, operator, amount).await;
             }
 
-            Operation::AddFollower => {
-                let count = self.state.follower_count.get();
-                self.state.follower_count.set(count + 1);
+            Operation::EnforceSchedule { bot_id } => {
+                load_active_bot(&mut self.state, &bot_id).await?;
+                let current_time = self.runtime.system_time().micros() / 1000; // Convert to ms
+                self.enforce_schedule(&bot_id, current_time).await;
+                self.enforce_reveals(&bot_id, current_time).await;
             }
 
-            Operation::RemoveFollower => {
-                let count = *self.state.follower_count.get();
-                if count > 0 {
-                    self.state.follower_count.set(count - 1);
+            Operation::GetLatestSignal { bot_id } => {
+                let signal = load_bot(&mut self.state, &bot_id).await?.latest_signal().await
+                    .expect("Failed to read latest signal");
+                return Ok(BotResponse::Signal(signal));
+            }
+
+            Operation::GetSignal { bot_id, id } => {
+                let id = BotStateError::parse_u64("signal id", &id)?;
+                let signal = load_bot(&mut self.state, &bot_id).await?.signals.get(&id).await
+                    .expect("Failed to read signal");
+                return Ok(BotResponse::Signal(signal));
+            }
+
+            Operation::GetMetrics {
+                bot_id,
+                window,
+                asset,
+            } => {
+                let current_time = self.runtime.system_time().micros() / 1000; // Convert to ms
+                let bot = load_bot(&mut self.state, &bot_id).await?;
+                let mut metrics = match asset {
+                    Some(asset) => bot.asset_window_accuracy(&asset, window).await,
+                    None => bot.window_accuracy(window).await,
                 }
+                .expect("Failed to read accuracy metrics");
+                metrics.advance_to(current_time);
+                return Ok(BotResponse::Metrics(metrics.summary()));
             }
         }
+
+        Ok(BotResponse::Done)
     }
 
-    async fn execute_message(&mut self, _message: Self::Message) {}
+    async fn execute_message(&mut self, message: Self::Message) {
+        let origin = self.runtime.message_origin_chain_id()
+            .expect("Incoming message must have an origin chain");
+        let bot_chain = self.runtime.application_creator_chain_id();
+        let on_bot_chain = self.runtime.chain_id() == bot_chain;
+
+        match message {
+            Message::Subscribe { bot_id } if on_bot_chain => {
+                // Subscriptions to unknown bots are ignored
+                if let Ok(bot) = load_bot(&mut self.state, &bot_id).await {
+                    bot.subscribers.insert(&origin)
+                        .expect("Failed to store subscriber");
+                }
+            }
+
+            Message::Unsubscribe { bot_id } if on_bot_chain => {
+                if let Ok(bot) = load_bot(&mut self.state, &bot_id).await {
+                    bot.subscribers.remove(&origin)
+                        .expect("Failed to remove subscriber");
+                }
+            }
+
+            Message::SignalPublished { bot_id, signal }
+            | Message::SignalResolved { bot_id, signal }
+                if !on_bot_chain =>
+            {
+                assert_eq!(origin, bot_chain, "Signals must come from the bot chain");
+                self.store_received_signal(&bot_id, signal).await;
+            }
+
+            // Bounced messages return to the chain that sent them; nothing to undo
+            _ => {}
+        }
+    }
+
+    async fn process_streams(&mut self, updates: Vec<StreamUpdate>) {
+        let bot_chain = self.runtime.application_creator_chain_id();
+        for update in updates {
+            assert_eq!(update.chain_id, bot_chain, "Events must come from the bot chain");
+            assert_eq!(update.stream_id.stream_name, StreamName::from(BOT_STREAM_NAME));
+            for index in update.new_indices() {
+                let event = self.runtime.read_event(
+                    update.chain_id,
+                    StreamName::from(BOT_STREAM_NAME),
+                    index,
+                );
+                match event {
+                    BotEvent::SignalSubmitted { bot_id, signal }
+                    | BotEvent::SignalResolved { bot_id, signal } => {
+                        self.store_received_signal(&bot_id, signal).await;
+                    }
+                    BotEvent::PredictionCommitted { .. } | BotEvent::MetricsUpdated { .. } => {}
+                }
+            }
+        }
+    }
 
     async fn store(mut self) {
         self.state.save().await.expect("Failed to save state");
     }
 }
 
+/// Load a bot hosted by this application, failing if it was never created
+async fn load_bot<'a>(state: &'a mut BotState, bot_id: &str) -> Result<&'a mut Bot, BotStateError> {
+    let exists = state.bots.try_load_entry(bot_id).await
+        .expect("Failed to read bots")
+        .is_some();
+    if !exists {
+        return Err(BotStateError::UnknownBot(bot_id.to_string()));
+    }
+    Ok(state.bots.load_entry_mut(bot_id).await
+        .expect("Failed to load bot"))
+}
+
+/// Load a bot that may still publish, failing if it is unknown or retired
+async fn load_active_bot<'a>(
+    state: &'a mut BotState,
+    bot_id: &str,
+) -> Result<&'a mut Bot, BotStateError> {
+    let bot = load_bot(state, bot_id).await?;
+    if *bot.retired.get() {
+        return Err(BotStateError::BotRetired(bot_id.to_string()));
+    }
+    Ok(bot)
+}
+
+impl BotStateContract {
+    /// Host a new bot; missing roles default to the signer
+    async fn create_bot(
+        &mut self,
+        bot_id: String,
+        operator: Option<AccountOwner>,
+        resolver: Option<AccountOwner>,
+        stake: Amount,
+        slashing_policy: Option<SlashingPolicy>,
+    ) -> Result<(), BotStateError> {
+        Bot::validate_id(&bot_id)?;
+        let exists = self.state.bots.try_load_entry(&bot_id).await
+            .expect("Failed to read bots")
+            .is_some();
+        if exists {
+            return Err(BotStateError::BotAlreadyExists(bot_id));
+        }
+        if let Some(policy) = &slashing_policy {
+            policy.validate()?;
+        }
+        let signer = self.runtime.authenticated_signer();
+        if stake > Amount::ZERO && signer.is_none() {
+            return Err(BotStateError::MissingSigner);
+        }
+        // Register first: an error does not undo changes already made
+        self.call_registry(bot_registry::Operation::Register { bot_id: bot_id.clone() })?;
+
+        // The signal schedule starts when the bot is created
+        let current_time = self.runtime.system_time().micros() / 1000; // Convert to ms
+        let bot = self.state.bots.load_entry_mut(&bot_id).await
+            .expect("Failed to load bot");
+        bot.bot_id.set(bot_id.clone());
+        bot.operator.set(operator.or(signer));
+        bot.resolver.set(resolver.or(signer));
+        bot.slashing_policy.set(slashing_policy);
+        bot.missed_deadline_slashed.set(current_time);
+        if let Some(signer) = signer.filter(|_| stake > Amount::ZERO) {
+            self.deposit_stake(&bot_id, signer, stake).await;
+        }
+        Ok(())
+    }
+
+    /// Load a bot whose existence was already checked
+    async fn bot(&mut self, bot_id: &str) -> &mut Bot {
+        self.state.bots.load_entry_mut(bot_id).await
+            .expect("Failed to load bot")
+    }
+
+    /// Store a signal received from the bot chain (on follower chains)
+    async fn store_received_signal(&mut self, bot_id: &str, signal: Signal) {
+        let id = signal.id;
+        self.state.received_signals.load_entry_mut(bot_id).await
+            .expect("Failed to load received signals")
+            .insert(&id, signal)
+            .expect("Failed to store received signal");
+    }
+
+    /// Append a signal to a bot's history and notify subscribers, returning its id
+    async fn publish_signal(&mut self, bot_id: &str, mut signal: Signal) -> u64 {
+        signal.id = self.bot(bot_id).await.push_signal(signal.clone()).await
+            .expect("Failed to store signal");
+        self.runtime.emit(
+            StreamName::from(BOT_STREAM_NAME),
+            &BotEvent::SignalSubmitted {
+                bot_id: bot_id.to_string(),
+                signal: signal.clone(),
+            },
+        );
+        let id = signal.id;
+        let message = Message::SignalPublished {
+            bot_id: bot_id.to_string(),
+            signal,
+        };
+        self.broadcast(bot_id, message).await;
+        id
+    }
+
+    /// Send a tracked message to every chain subscribed to a bot
+    async fn broadcast(&mut self, bot_id: &str, message: Message) {
+        let subscribers = self.bot(bot_id).await.subscribers.indices().await
+            .expect("Failed to read subscribers");
+        for chain_id in subscribers {
+            self.runtime.prepare_message(message.clone())
+                .with_tracking()
+                .send_to(chain_id);
+        }
+    }
+
+    /// Send a bot's latest metrics and follower count to the bot registry, if configured
+    /// and the bot is not retired
+    ///
+    /// Reports are best-effort: a rejected report must not fail the operation that
+    /// triggered it, whose changes are kept anyway.
+    async fn report_to_registry(&mut self, bot_id: &str) {
+        if self.state.registry.get().is_none() || *self.bot(bot_id).await.retired.get() {
+            return;
+        }
+        let current_time = self.runtime.system_time().micros() / 1000; // Convert to ms
+        let operation = bot_registry::Operation::ReportMetrics {
+            bot_id: bot_id.to_string(),
+            report: self.bot(bot_id).await.registry_report(current_time).await
+                .expect("Failed to read metrics"),
+        };
+        let _ = self.call_registry(operation);
+    }
+
+    /// Send an operation to the bot registry, if configured
+    fn call_registry(&mut self, operation: bot_registry::Operation) -> Result<(), BotStateError> {
+        let Some(registry) = *self.state.registry.get() else {
+            return Ok(());
+        };
+        self.runtime
+            .call_application(true, registry.with_abi::<RegistryAbi>(), &operation)
+            .map_err(BotStateError::Registry)
+    }
+
+    /// Get the application's own account on the current chain, which holds every bot's
+    /// stake and subscription revenue
+    fn application_account(&mut self) -> Account {
+        Account {
+            chain_id: self.runtime.chain_id(),
+            owner: self.runtime.application_id().into(),
+        }
+    }
+
+    /// Move `amount` from `owner`'s account into a bot's stake
+    async fn deposit_stake(&mut self, bot_id: &str, owner: AccountOwner, amount: Amount) {
+        let application_account = self.application_account();
+        self.runtime.transfer(owner, application_account, amount);
+        let bot = self.bot(bot_id).await;
+        let stake = bot.stake.get().saturating_add(amount);
+        bot.stake.set(stake);
+    }
+
+    /// Slash a bot's stake if its policy's accuracy window fell below the threshold
+    async fn enforce_accuracy(&mut self, bot_id: &str, metrics: &[AccuracyMetrics], now: u64) {
+        let Some(policy) = self.bot(bot_id).await.slashing_policy.get().clone() else {
+            return;
+        };
+        let window_metrics = metrics.iter().find(|m| m.window == policy.accuracy_window);
+        if let Some(window_metrics) = window_metrics {
+            if policy.is_accuracy_violated(window_metrics) {
+                let accuracy_bps = window_metrics.directional_accuracy_bps;
+                self.slash(bot_id, SlashReason::LowAccuracy, Some(accuracy_bps), None, now)
+                    .await;
+            }
+        }
+    }
+
+    /// Score a bot's commitments whose target timestamp passed without a reveal as wrong
+    /// calls, so an operator cannot commit to several outcomes and reveal only the winner
+    async fn enforce_reveals(&mut self, bot_id: &str, now: u64) {
+        let missed = self.bot(bot_id).await.take_missed_commitments(now).await
+            .expect("Failed to read commitments");
+        let mut metrics = Vec::new();
+        for commitment in &missed {
+            metrics = self.bot(bot_id).await.record_missed_reveal(commitment, now).await
+                .expect("Failed to update accuracy metrics");
+        }
+        if !missed.is_empty() {
+            self.publish_metrics(bot_id, &metrics, now).await;
+        }
+    }
+
+    /// Apply the accuracy slashing rule to a bot's updated metrics, then publish them on
+    /// the event stream and to the bot registry
+    async fn publish_metrics(&mut self, bot_id: &str, metrics: &[AccuracyMetrics], now: u64) {
+        self.enforce_accuracy(bot_id, metrics, now).await;
+        let summaries = metrics.iter().map(|m| m.summary()).collect();
+        self.runtime.emit(
+            StreamName::from(BOT_STREAM_NAME),
+            &BotEvent::MetricsUpdated {
+                bot_id: bot_id.to_string(),
+                metrics: summaries,
+            },
+        );
+        self.report_to_registry(bot_id).await;
+    }
+
+    /// Slash a bot's stake once if a signal deadline passed without a new signal
+    async fn enforce_schedule(&mut self, bot_id: &str, now: u64) {
+        let bot = self.bot(bot_id).await;
+        let Some(policy) = bot.slashing_policy.get().clone() else {
+            return;
+        };
+        // Chain time of acceptance, not the client-supplied signal timestamp, starts the
+        // next interval. Commitments count as signals, even before they are revealed.
+        let last_signal_at = *bot.last_signal_at.get();
+        let last_slashed = *bot.missed_deadline_slashed.get();
+        if let Some(deadline) = policy.missed_deadline(last_signal_at, last_slashed, now) {
+            bot.missed_deadline_slashed.set(deadline);
+            self.slash(bot_id, SlashReason::MissedSignal, None, Some(deadline), now).await;
+        }
+    }
+
+    /// Send a share of a bot's stake to its policy's beneficiary and record the slash
+    async fn slash(
+        &mut self,
+        bot_id: &str,
+        reason: SlashReason,
+        accuracy_bps: Option<u64>,
+        missed_deadline: Option<u64>,
+        now: u64,
+    ) {
+        let bot = self.bot(bot_id).await;
+        let policy = bot.slashing_policy.get().clone()
+            .expect("Slashing requires a policy");
+        let stake = *bot.stake.get();
+        let amount = policy.slash_amount(stake);
+        if amount == Amount::ZERO {
+            return;
+        }
+
+        let application_owner = self.runtime.application_id().into();
+        self.runtime.transfer(application_owner, policy.beneficiary, amount);
+        let bot = self.bot(bot_id).await;
+        bot.stake.set(stake.saturating_sub(amount));
+        bot.slashes.push(Slash {
+            timestamp: now,
+            reason,
+            amount,
+            accuracy_bps,
+            missed_deadline,
+        });
+    }
+
+    /// Get the bot chain, failing if the operation runs on the bot chain itself
+    fn follower_side_bot_chain(&mut self) -> Result<ChainId, BotStateError> {
+        let bot_chain = self.runtime.application_creator_chain_id();
+        if self.runtime.chain_id() == bot_chain {
+            return Err(BotStateError::SubscribeFromBotChain);
+        }
+        Ok(bot_chain)
+    }
+
+    /// Send a subscription request from a follower chain to the bot chain
+    fn send_to_bot_chain(&mut self, message: Message) -> Result<(), BotStateError> {
+        let bot_chain = self.follower_side_bot_chain()?;
+        self.runtime.prepare_message(message)
+            .with_tracking()
+            .send_to(bot_chain);
+        Ok(())
+    }
+
+    /// Check that signals may be published for `asset` under the application parameters
+    fn check_asset(&mut self, asset: &str) -> Result<(), BotStateError> {
+        Signal::validate_asset(asset)?;
+        if !self.runtime.application_parameters().supports(asset) {
+            return Err(BotStateError::UnsupportedAsset(asset.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Check that the operation was signed by a bot's operator
+    async fn check_operator(&mut self, bot_id: &str) -> Result<(), BotStateError> {
+        let operator = *load_bot(&mut self.state, bot_id).await?.operator.get();
+        self.check_signer("operator", operator)
+    }
+
+    /// Check that the operation was signed by a bot's resolver
+    async fn check_resolver(&mut self, bot_id: &str) -> Result<(), BotStateError> {
+        let resolver = *load_bot(&mut self.state, bot_id).await?.resolver.get();
+        self.check_signer("resolver", resolver)
+    }
+
+    /// Check that the operation was signed by the account holding `role`
+    fn check_signer(
+        &mut self,
+        role: &str,
+        expected: Option<AccountOwner>,
+    ) -> Result<(), BotStateError> {
+        let signer = self.runtime.authenticated_signer()
+            .ok_or(BotStateError::MissingSigner)?;
+        if Some(signer) != expected {
+            return Err(BotStateError::Unauthorized {
+                role: role.to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use futures::FutureExt as _;
-    use linera_sdk::{util::BlockingWait, views::View, Contract, ContractRuntime};
+    use std::{cell::RefCell, rc::Rc};
+
+    use bot_registry::{Operation as RegistryOperation, RegistryError};
+    use linera_sdk::{
+        bcs,
+        linera_base_types::{
+            Account, AccountOwner, Amount, ApplicationId, ChainId, CryptoHash,
+            GenericApplicationId, StreamId, StreamName, StreamUpdate, Timestamp,
+        },
+        util::BlockingWait,
+        views::View,
+        Contract, ContractRuntime,
+    };
 
-    use bot_state::{Action, Operation};
+    use bot_state::{
+        Action, Bot, BotEvent, BotParameters, BotResponse, BotStateAbi, BotStateError,
+        CommittedPrediction, InstantiationArgument, Message, MetricsWindow, Operation,
+        Signal, SlashReason, SlashingPolicy, BOT_STREAM_NAME, MAX_TIMESTAMP_DRIFT_MS,
+        RESOLUTION_GRACE_MS,
+    };
 
     use super::{BotState, BotStateContract};
 
@@ -143,51 +985,1282 @@ mod tests {
         let bot_id = "test-bot".to_string();
         let mut app = create_and_instantiate_app(bot_id.clone());
 
-        app.execute_operation(Operation::SubmitPrediction {
-            timestamp: 1000000,
+        execute(&mut app, Operation::SubmitPrediction {
+            bot_id: BOT_ID.to_string(),
+            asset: ASSET.to_string(),
+            timestamp: "1000000".to_string(),
+            horizon_ms: "3600000".to_string(),
             action: Action::Buy,
-            predicted_price: 2500.0,
-            confidence: 0.75,
+            reference_price_micro: "2450000000".to_string(),
+            predicted_price_micro: "2500000000".to_string(),
+            confidence_bps: 7500,
             reasoning: "Bullish trend detected".to_string(),
         })
-        .now_or_never()
-        .expect("Execution should not await anything");
+        .expect("Submission should succeed");
 
-        let latest = app.state.latest_signal.get();
+        let latest = bot(&mut app).latest_signal()
+            .blocking_wait()
+            .expect("Failed to read latest signal");
         assert!(latest.is_some());
         assert_eq!(latest.as_ref().unwrap().timestamp, 1000000);
         assert_eq!(latest.as_ref().unwrap().action, Action::Buy);
     }
 
+    #[test]
+    fn test_signal_history_is_kept() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+
+        for (i, action) in [Action::Buy, Action::Sell, Action::Hold].into_iter().enumerate() {
+            execute(&mut app, Operation::SubmitPrediction {
+                bot_id: BOT_ID.to_string(),
+                asset: ASSET.to_string(),
+                timestamp: (1000000 + i as u64).to_string(),
+                horizon_ms: "3600000".to_string(),
+                action,
+                reference_price_micro: "2450000000".to_string(),
+                predicted_price_micro: "2500000000".to_string(),
+                confidence_bps: 7500,
+                reasoning: "Test".to_string(),
+            })
+            .expect("Submission should succeed");
+        }
+
+        assert_eq!(*bot(&mut app).next_signal_id.get(), 3);
+        let first = bot(&mut app).signals.get(&0)
+            .blocking_wait()
+            .expect("Failed to read signal")
+            .expect("First signal should be kept");
+        assert_eq!(first.id, 0);
+        assert_eq!(first.action, Action::Buy);
+    }
+
+    #[test]
+    fn test_submit_invalid_input_is_rejected() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+
+        let result = execute(&mut app, Operation::SubmitPrediction {
+            bot_id: BOT_ID.to_string(),
+            asset: ASSET.to_string(),
+            timestamp: "not-a-number".to_string(),
+            horizon_ms: "3600000".to_string(),
+            action: Action::Buy,
+            reference_price_micro: "2450000000".to_string(),
+            predicted_price_micro: "2500000000".to_string(),
+            confidence_bps: 7500,
+            reasoning: "Test".to_string(),
+        });
+        assert!(matches!(result, Err(BotStateError::InvalidNumber { .. })));
+
+        let result = execute(&mut app, Operation::SubmitPrediction {
+            bot_id: BOT_ID.to_string(),
+            asset: ASSET.to_string(),
+            timestamp: "1000000".to_string(),
+            horizon_ms: "3600000".to_string(),
+            action: Action::Buy,
+            reference_price_micro: "2450000000".to_string(),
+            predicted_price_micro: "2500000000".to_string(),
+            confidence_bps: 10001,
+            reasoning: "Test".to_string(),
+        });
+        assert_eq!(result, Err(BotStateError::ConfidenceOutOfRange(10001)));
+
+        // Nothing was stored
+        assert_eq!(*bot(&mut app).next_signal_id.get(), 0);
+    }
+
+    #[test]
+    fn test_submit_non_monotonic_timestamp_is_rejected() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        submit(&mut app, "2000000").expect("Submission should succeed");
+
+        assert_eq!(
+            submit(&mut app, "2000000"),
+            Err(BotStateError::DuplicateTimestamp(2000000))
+        );
+        assert_eq!(
+            submit(&mut app, "1000000"),
+            Err(BotStateError::TimestampNotIncreasing {
+                timestamp: 1000000,
+                latest: 2000000,
+            })
+        );
+    }
+
+    #[test]
+    fn test_resolve_older_pending_signal() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        submit(&mut app, "1000000").expect("Submission should succeed");
+        submit(&mut app, "2000000").expect("Submission should succeed");
+
+        // The first signal is still resolvable after a newer one was submitted
+        resolve(&mut app, "1000000").expect("Resolution should succeed");
+
+        let resolved = bot(&mut app).signals.get(&0)
+            .blocking_wait()
+            .expect("Failed to read signal")
+            .expect("Signal should exist");
+        assert_eq!(resolved.actual_price_micro, Some(2_600_000_000));
+        // Cleartext submissions carry a client-supplied timestamp and are not scored
+        for window in MetricsWindow::ALL {
+            let metrics = bot(&mut app).window_accuracy(window)
+                .blocking_wait()
+                .expect("Failed to read metrics");
+            assert_eq!(metrics.total_predictions, 0);
+        }
+
+        let pending = bot(&mut app).unresolved_signals()
+            .blocking_wait()
+            .expect("Failed to read pending signals");
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].timestamp, 2000000);
+    }
+
+    #[test]
+    fn test_resolve_twice_fails() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        submit(&mut app, "1000000").expect("Submission should succeed");
+
+        resolve(&mut app, "1000000").expect("Resolution should succeed");
+        assert_eq!(
+            resolve(&mut app, "1000000"),
+            Err(BotStateError::AlreadyResolved(0))
+        );
+    }
+
+    #[test]
+    fn test_resolve_unknown_signal_fails() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+
+        assert_eq!(
+            resolve(&mut app, "1000000"),
+            Err(BotStateError::UnknownSignal(1000000))
+        );
+    }
+
+    #[test]
+    fn test_resolve_before_target_fails() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        // Target is one hour after the mocked current time
+        submit_with_horizon(&mut app, "1000000", NOW_MS + 3_600_000 - 1000000)
+            .expect("Submission should succeed");
+
+        assert_eq!(
+            resolve(&mut app, "1000000"),
+            Err(BotStateError::OutsideResolutionWindow {
+                id: 0,
+                target_timestamp: NOW_MS + 3_600_000,
+                grace_ms: RESOLUTION_GRACE_MS,
+            })
+        );
+    }
+
+    #[test]
+    fn test_overdue_signals() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        // Target long past
+        submit_with_horizon(&mut app, "1000000", 1000).expect("Submission should succeed");
+        // Target in the future
+        submit_with_horizon(&mut app, "2000000", 3_600_000).expect("Submission should succeed");
+
+        let overdue = bot(&mut app).overdue_signals(NOW_MS)
+            .blocking_wait()
+            .expect("Failed to read overdue signals");
+        assert_eq!(overdue.len(), 1);
+        assert_eq!(overdue[0].target_timestamp, 1001000);
+    }
+
+    #[test]
+    fn test_read_operations() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        assert_eq!(
+            execute(&mut app, Operation::GetLatestSignal { bot_id: BOT_ID.to_string() }),
+            Ok(BotResponse::Signal(None))
+        );
+
+        let first_timestamp = commit_and_reveal(&mut app, NOW_MS - 1000, 1000);
+        submit(&mut app, "2999500").expect("Submission should succeed");
+        resolve(&mut app, &first_timestamp.to_string()).expect("Resolution should succeed");
+
+        // Reads need no role, so another application or account can call them
+        app.runtime.set_authenticated_signer(None);
+        let latest_signal = Operation::GetLatestSignal { bot_id: BOT_ID.to_string() };
+        let Ok(BotResponse::Signal(Some(latest))) = execute(&mut app, latest_signal) else {
+            panic!("Expected the latest signal");
+        };
+        assert_eq!(latest.id, 1);
+
+        let Ok(BotResponse::Signal(Some(first))) = execute(&mut app, get_signal("0")) else {
+            panic!("Expected signal 0");
+        };
+        assert_eq!(first.actual_price_micro, Some(2_600_000_000));
+        assert_eq!(execute(&mut app, get_signal("7")), Ok(BotResponse::Signal(None)));
+
+        let Ok(BotResponse::Metrics(metrics)) = execute(&mut app, Operation::GetMetrics {
+            bot_id: BOT_ID.to_string(),
+            window: MetricsWindow::OneDay,
+            asset: None,
+        }) else {
+            panic!("Expected metrics");
+        };
+        assert_eq!(metrics.total_predictions, 1);
+        assert!(metrics.buckets.is_empty());
+    }
+
+    #[test]
+    fn test_commit_reveal_prediction_is_scored() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        let committed_at = NOW_MS - 2000;
+        app.runtime.set_system_time(Timestamp::from(committed_at * 1000));
+        execute(&mut app, Operation::CommitPrediction {
+            bot_id: BOT_ID.to_string(),
+            asset: ASSET.to_string(),
+            commitment: prediction("secret").hash(),
+            horizon_ms: "2000".to_string(),
+        })
+        .expect("Commitment should succeed");
+
+        // Nothing is published until the prediction is revealed
+        assert_eq!(*bot(&mut app).next_signal_id.get(), 0);
+        assert_eq!(
+            reveal(&mut app, 0, prediction("guess")),
+            Err(BotStateError::CommitmentMismatch(0))
+        );
+        // The reasoning is committed too, and so are the bot and chain
+        let swapped = CommittedPrediction {
+            reasoning: "Hindsight".to_string(),
+            ..prediction("secret")
+        };
+        assert_eq!(reveal(&mut app, 0, swapped), Err(BotStateError::CommitmentMismatch(0)));
+        let other_bot = CommittedPrediction {
+            bot_id: "sentiment".to_string(),
+            ..prediction("secret")
+        };
+        assert_ne!(other_bot.hash(), prediction("secret").hash());
+        assert_eq!(
+            reveal(&mut app, 1, prediction("secret")),
+            Err(BotStateError::UnknownCommitment(1))
+        );
+
+        reveal(&mut app, 0, prediction("secret")).expect("Reveal should succeed");
+        assert_eq!(
+            reveal(&mut app, 0, prediction("secret")),
+            Err(BotStateError::AlreadyRevealed(0))
+        );
+
+        let signal = bot(&mut app).latest_signal()
+            .blocking_wait()
+            .expect("Failed to read latest signal")
+            .expect("Revealed signal should be stored");
+        // The chain's time at commitment is the signal timestamp
+        assert_eq!(signal.timestamp, committed_at);
+        assert_eq!(signal.target_timestamp, NOW_MS);
+        assert_eq!(signal.commitment, Some(prediction("secret").hash()));
+
+        app.runtime.set_system_time(Timestamp::from(NOW_MS * 1000));
+        resolve(&mut app, &committed_at.to_string()).expect("Resolution should succeed");
+        for window in MetricsWindow::ALL {
+            let metrics = bot(&mut app).window_accuracy(window)
+                .blocking_wait()
+                .expect("Failed to read metrics");
+            assert_eq!(metrics.total_predictions, 1);
+        }
+    }
+
+    #[test]
+    fn test_commitments_must_be_revealed_in_time() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        let commitment = Operation::CommitPrediction {
+            bot_id: BOT_ID.to_string(),
+            asset: ASSET.to_string(),
+            commitment: prediction("secret").hash(),
+            horizon_ms: "1000".to_string(),
+        };
+        execute(&mut app, commitment).expect("Commitment should succeed");
+
+        // Two commitments cannot share a timestamp
+        assert_eq!(
+            execute(&mut app, Operation::CommitPrediction {
+                bot_id: BOT_ID.to_string(),
+                asset: ASSET.to_string(),
+                commitment: prediction("other").hash(),
+                horizon_ms: "1000".to_string(),
+            }),
+            Err(BotStateError::DuplicateTimestamp(NOW_MS))
+        );
+
+        app.runtime.set_system_time(Timestamp::from((NOW_MS + 1001) * 1000));
+        assert_eq!(
+            reveal(&mut app, 0, prediction("secret")),
+            Err(BotStateError::RevealTooLate {
+                id: 0,
+                target_timestamp: NOW_MS + 1000,
+            })
+        );
+
+        let unrevealed = bot(&mut app).unrevealed_commitments(NOW_MS + 1001)
+            .blocking_wait()
+            .expect("Failed to read commitments");
+        assert_eq!(unrevealed.len(), 1);
+
+        // The unrevealed commitment counts as a wrong call, once
+        for _ in 0..2 {
+            execute(&mut app, Operation::EnforceSchedule { bot_id: BOT_ID.to_string() })
+                .expect("Enforcement should succeed");
+            for window in MetricsWindow::ALL {
+                let metrics = bot(&mut app).window_accuracy(window)
+                    .blocking_wait()
+                    .expect("Failed to read metrics");
+                assert_eq!(metrics.total_predictions, 1);
+                assert_eq!(metrics.missed_predictions, 1);
+                assert_eq!(metrics.correct_predictions, 0);
+                assert_eq!(metrics.rmse_micro, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_signals_are_tracked_per_asset() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        let committed_at = NOW_MS - 1000;
+        let btc = CommittedPrediction {
+            asset: "BTCUSDT".to_string(),
+            ..prediction("salt")
+        };
+
+        // Commitments for different assets may share a timestamp
+        app.runtime.set_system_time(Timestamp::from(committed_at * 1000));
+        for prediction in [prediction("salt"), btc.clone()] {
+            execute(&mut app, Operation::CommitPrediction {
+                bot_id: BOT_ID.to_string(),
+                asset: prediction.asset.clone(),
+                commitment: prediction.hash(),
+                horizon_ms: "1000".to_string(),
+            })
+            .expect("Commitment should succeed");
+        }
+        assert_eq!(
+            execute(&mut app, Operation::CommitPrediction {
+                bot_id: BOT_ID.to_string(),
+                asset: ASSET.to_string(),
+                commitment: prediction("other").hash(),
+                horizon_ms: "1000".to_string(),
+            }),
+            Err(BotStateError::DuplicateTimestamp(committed_at))
+        );
+        reveal(&mut app, 0, prediction("salt")).expect("Reveal should succeed");
+        reveal(&mut app, 1, btc).expect("Reveal should succeed");
+
+        app.runtime.set_system_time(Timestamp::from(NOW_MS * 1000));
+        resolve(&mut app, &committed_at.to_string()).expect("Resolution should succeed");
+        app.runtime.set_authenticated_signer(Some(owner("resolver")));
+        execute(&mut app, Operation::ResolveSignal {
+            bot_id: BOT_ID.to_string(),
+            asset: "BTCUSDT".to_string(),
+            timestamp: committed_at.to_string(),
+            actual_price_micro: "2600000000".to_string(),
+        })
+        .expect("Resolution should succeed");
+
+        let metrics = |app: &mut BotStateContract, asset: Option<&str>| {
+            let operation = Operation::GetMetrics {
+                bot_id: BOT_ID.to_string(),
+                window: MetricsWindow::AllTime,
+                asset: asset.map(str::to_string),
+            };
+            let Ok(BotResponse::Metrics(metrics)) = execute(app, operation) else {
+                panic!("Expected metrics");
+            };
+            metrics.total_predictions
+        };
+        assert_eq!(metrics(&mut app, None), 2);
+        assert_eq!(metrics(&mut app, Some("BTCUSDT")), 1);
+        assert_eq!(metrics(&mut app, Some("SOLUSDT")), 0);
+
+        let mut assets = bot(&mut app).assets()
+            .blocking_wait()
+            .expect("Failed to read assets");
+        assets.sort();
+        assert_eq!(assets, vec!["BTCUSDT".to_string(), ASSET.to_string()]);
+    }
+
+    #[test]
+    fn test_assets_must_be_configured() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+
+        assert_eq!(
+            execute(&mut app, Operation::CommitPrediction {
+                bot_id: BOT_ID.to_string(),
+                asset: "DOGEUSDT".to_string(),
+                commitment: prediction("secret").hash(),
+                horizon_ms: "1000".to_string(),
+            }),
+            Err(BotStateError::UnsupportedAsset("DOGEUSDT".to_string()))
+        );
+        assert_eq!(
+            execute(&mut app, Operation::CommitPrediction {
+                bot_id: BOT_ID.to_string(),
+                asset: "eth".to_string(),
+                commitment: prediction("secret").hash(),
+                horizon_ms: "1000".to_string(),
+            }),
+            Err(BotStateError::InvalidAsset("eth".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_submit_requires_operator() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        app.runtime.set_authenticated_signer(Some(owner("stranger")));
+
+        assert_eq!(
+            submit(&mut app, "1000000"),
+            Err(BotStateError::Unauthorized { role: "operator".to_string() })
+        );
+    }
+
+    #[test]
+    fn test_resolve_requires_resolver() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        submit(&mut app, "1000000").expect("Submission should succeed");
+
+        // Still signed by the operator
+        let result = execute(&mut app, Operation::ResolveSignal {
+            bot_id: BOT_ID.to_string(),
+            asset: ASSET.to_string(),
+            timestamp: "1000000".to_string(),
+            actual_price_micro: "2600000000".to_string(),
+        });
+        assert_eq!(
+            result,
+            Err(BotStateError::Unauthorized { role: "resolver".to_string() })
+        );
+    }
+
+    #[test]
+    fn test_stake_requires_operator_and_positive_amount() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+
+        assert_eq!(
+            execute(&mut app, stake(Amount::ZERO)),
+            Err(BotStateError::ZeroStake)
+        );
+
+        app.runtime.set_authenticated_signer(Some(owner("mallory")));
+        assert_eq!(
+            execute(&mut app, stake(Amount::from_tokens(1))),
+            Err(BotStateError::Unauthorized { role: "operator".to_string() })
+        );
+        assert_eq!(*bot(&mut app).stake.get(), Amount::ZERO);
+    }
+
+    #[test]
+    fn test_role_rotation() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        let new_operator = owner("new-operator");
+        let new_resolver = owner("new-resolver");
+
+        execute(&mut app, Operation::SetResolver { bot_id: BOT_ID.to_string(), new_resolver })
+            .expect("Operator may rotate the resolver");
+        execute(&mut app, Operation::TransferOperator { bot_id: BOT_ID.to_string(), new_operator })
+            .expect("Operator may transfer the role");
+
+        assert_eq!(*bot(&mut app).operator.get(), Some(new_operator));
+        assert_eq!(*bot(&mut app).resolver.get(), Some(new_resolver));
+
+        // The previous operator can no longer submit
+        assert!(matches!(
+            submit(&mut app, "1000000"),
+            Err(BotStateError::Unauthorized { .. })
+        ));
+    }
+
     #[test]
     fn test_follower_count() {
         let bot_id = "test-bot".to_string();
         let mut app = create_and_instantiate_app(bot_id);
+        add_free_tier(&mut app);
 
         // Add follower
-        app.execute_operation(Operation::AddFollower)
-            .now_or_never()
-            .expect("Execution should not await anything");
+        follow(&mut app, "alice", free_subscription(1));
+        assert_eq!(follower_count(&mut app), 1);
 
-        assert_eq!(*app.state.follower_count.get(), 1);
+        // Renewing does not count twice
+        follow(&mut app, "alice", free_subscription(1));
+        assert_eq!(follower_count(&mut app), 1);
 
         // Add another
-        app.execute_operation(Operation::AddFollower)
-            .now_or_never()
-            .expect("Execution should not await anything");
+        follow(&mut app, "bob", free_subscription(1));
+        assert_eq!(follower_count(&mut app), 2);
 
-        assert_eq!(*app.state.follower_count.get(), 2);
+        // Removing an account that is not following changes nothing
+        follow(&mut app, "carol", Operation::RemoveFollower { bot_id: BOT_ID.to_string() });
+        assert_eq!(follower_count(&mut app), 2);
 
         // Remove one
-        app.execute_operation(Operation::RemoveFollower)
-            .now_or_never()
-            .expect("Execution should not await anything");
+        follow(&mut app, "alice", Operation::RemoveFollower { bot_id: BOT_ID.to_string() });
+        assert_eq!(follower_count(&mut app), 1);
+
+        let bob = bot(&mut app).followers.get(&owner("bob"))
+            .blocking_wait()
+            .expect("Failed to read follower")
+            .expect("Bob should be following");
+        assert_eq!(bob.followed_at, NOW_MS);
+        assert_eq!(bob.expires_at, NOW_MS + DAY_MS);
+    }
+
+    #[test]
+    fn test_subscriptions_expire() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        add_free_tier(&mut app);
+
+        follow(&mut app, "alice", free_subscription(1));
+        // Renewing an active subscription extends it from its expiry
+        follow(&mut app, "alice", free_subscription(2));
+        follow(&mut app, "bob", free_subscription(1));
+        follow(&mut app, "carol", free_subscription(1));
+        assert_eq!(follower_count(&mut app), 3);
+
+        // Bob's and Carol's day is over, Alice paid for three
+        app.runtime.set_system_time(Timestamp::from((NOW_MS + DAY_MS) * 1000));
+        assert_eq!(follower_count(&mut app), 1);
+
+        // Following again prunes expired entries and starts a fresh subscription for Bob
+        follow(&mut app, "bob", free_subscription(1));
+        let bob = bot(&mut app).followers.get(&owner("bob"))
+            .blocking_wait()
+            .expect("Failed to read follower")
+            .expect("Bob should be following");
+        assert_eq!(bob.followed_at, NOW_MS + DAY_MS);
+        assert_eq!(bob.expires_at, NOW_MS + 2 * DAY_MS);
+        let carol = bot(&mut app).followers.get(&owner("carol"))
+            .blocking_wait()
+            .expect("Failed to read follower");
+        assert_eq!(carol, None);
+        assert_eq!(*bot(&mut app).follower_entries.get(), 2);
+        assert_eq!(follower_count(&mut app), 2);
+    }
+
+    #[test]
+    fn test_subscription_tiers_are_validated() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+
+        app.runtime.set_authenticated_signer(Some(owner("alice")));
+        assert_eq!(
+            execute(&mut app, free_subscription(1)),
+            Err(BotStateError::UnknownTier("free".to_string()))
+        );
+        assert!(matches!(
+            execute(&mut app, Operation::SetSubscriptionTier {
+                bot_id: BOT_ID.to_string(),
+                name: "free".to_string(),
+                price: Amount::ZERO,
+                period_ms: DAY_MS.to_string(),
+            }),
+            Err(BotStateError::Unauthorized { .. })
+        ));
+
+        app.runtime.set_authenticated_signer(Some(owner("operator")));
+        assert_eq!(
+            execute(&mut app, Operation::SetSubscriptionTier {
+                bot_id: BOT_ID.to_string(),
+                name: "free".to_string(),
+                price: Amount::ZERO,
+                period_ms: "0".to_string(),
+            }),
+            Err(BotStateError::ZeroTierPeriod)
+        );
+
+        add_free_tier(&mut app);
+        app.runtime.set_authenticated_signer(Some(owner("alice")));
+        assert_eq!(execute(&mut app, free_subscription(0)), Err(BotStateError::ZeroPeriods));
+    }
+
+    #[test]
+    fn test_withdraw_is_limited_to_revenue() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        let recipient = Account {
+            chain_id: chain("bot"),
+            owner: owner("operator"),
+        };
+
+        assert_eq!(
+            execute(&mut app, Operation::WithdrawRevenue {
+                bot_id: BOT_ID.to_string(),
+                amount: Amount::from_tokens(1),
+                recipient,
+            }),
+            Err(BotStateError::InsufficientRevenue {
+                requested: Amount::from_tokens(1),
+                available: Amount::ZERO,
+            })
+        );
+    }
+
+    #[test]
+    fn test_subscriber_chains_are_tracked_on_bot_chain() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        let follower_chain = chain("follower");
+
+        app.runtime.set_message_origin_chain_id(Some(follower_chain));
+        app.execute_message(Message::Subscribe { bot_id: BOT_ID.to_string() })
+            .blocking_wait();
+
+        let subscribed = bot(&mut app).subscribers.contains(&follower_chain)
+            .blocking_wait()
+            .expect("Failed to read subscribers");
+        assert!(subscribed);
+
+        app.execute_message(Message::Unsubscribe { bot_id: BOT_ID.to_string() })
+            .blocking_wait();
+
+        let subscribed = bot(&mut app).subscribers.contains(&follower_chain)
+            .blocking_wait()
+            .expect("Failed to read subscribers");
+        assert!(!subscribed);
+    }
+
+    #[test]
+    fn test_follower_chain_stores_received_signals() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        submit(&mut app, "1000000").expect("Submission should succeed");
+        let signal = bot(&mut app).latest_signal()
+            .blocking_wait()
+            .expect("Failed to read latest signal")
+            .expect("Signal should exist");
+
+        // Pretend the same application now runs on a follower chain
+        app.runtime.set_chain_id(chain("follower"));
+        app.runtime.set_message_origin_chain_id(Some(chain("bot")));
+        app.execute_message(Message::SignalPublished {
+            bot_id: BOT_ID.to_string(),
+            signal: signal.clone(),
+        })
+            .blocking_wait();
+
+        let received = app.state.received_signals.load_entry_mut(BOT_ID)
+            .blocking_wait()
+            .expect("Failed to load received signals")
+            .get(&signal.id)
+            .blocking_wait()
+            .expect("Failed to read received signal");
+        assert_eq!(received, Some(signal));
+    }
+
+    #[test]
+    fn test_resolved_signals_are_streamed_to_followers() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        let committed_at = commit_and_reveal(&mut app, NOW_MS - 1000, 1000);
+        resolve(&mut app, &committed_at.to_string()).expect("Resolution should succeed");
+        let resolved = stored_signal(&mut app, 0);
+        assert_eq!(resolved.actual_price_micro, Some(2_600_000_000));
+
+        // The mock runtime only tells the index of the next event: the commitment, the
+        // revealed signal, the resolved signal and the metrics came before this one
+        let next_index = app.runtime.emit(
+            StreamName::from(BOT_STREAM_NAME),
+            &BotEvent::SignalResolved {
+                bot_id: BOT_ID.to_string(),
+                signal: resolved.clone(),
+            },
+        );
+        assert_eq!(next_index, 4);
+
+        // Pretend the same application now runs on a follower chain reading the bot chain's
+        // stream
+        app.runtime.set_chain_id(chain("follower"));
+        let event = BotEvent::SignalResolved {
+            bot_id: BOT_ID.to_string(),
+            signal: resolved.clone(),
+        };
+        let bytes = bcs::to_bytes(&event).expect("Failed to serialize event");
+        app.runtime.add_event(chain("bot"), StreamName::from(BOT_STREAM_NAME), 2, &bytes);
+        app.process_streams(vec![stream_update(chain("bot"), 2, 3)])
+            .blocking_wait();
+
+        let received = app.state.received_signals.load_entry_mut(BOT_ID)
+            .blocking_wait()
+            .expect("Failed to load received signals")
+            .get(&resolved.id)
+            .blocking_wait()
+            .expect("Failed to read received signal");
+        assert_eq!(received, Some(resolved));
+    }
+
+    #[test]
+    #[should_panic(expected = "Events must come from the bot chain")]
+    fn test_follower_rejects_events_from_other_chains() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        submit(&mut app, "1000000").expect("Submission should succeed");
+        let signal = stored_signal(&mut app, 0);
+
+        app.runtime.set_chain_id(chain("follower"));
+        let event = BotEvent::SignalSubmitted {
+            bot_id: BOT_ID.to_string(),
+            signal,
+        };
+        let bytes = bcs::to_bytes(&event).expect("Failed to serialize event");
+        app.runtime.add_event(chain("impostor"), StreamName::from(BOT_STREAM_NAME), 0, &bytes);
+        app.process_streams(vec![stream_update(chain("impostor"), 0, 1)])
+            .blocking_wait();
+    }
+
+    #[test]
+    fn test_subscribe_from_bot_chain_is_rejected() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+
+        assert_eq!(
+            execute(&mut app, Operation::Subscribe { bot_id: BOT_ID.to_string() }),
+            Err(BotStateError::SubscribeFromBotChain)
+        );
+        assert_eq!(
+            execute(&mut app, Operation::SubscribeToEvents),
+            Err(BotStateError::SubscribeFromBotChain)
+        );
+    }
+
+    #[test]
+    fn test_bots_are_independent() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        execute(&mut app, Operation::CreateBot {
+            bot_id: "sentiment".to_string(),
+            operator: Some(owner("other-operator")),
+            resolver: None,
+            stake: Amount::ZERO,
+            slashing_policy: None,
+        })
+        .expect("Admin may create bots");
+
+        submit(&mut app, "1000000").expect("Submission should succeed");
+        // The first bot's operator has no role on the new bot
+        assert_eq!(
+            execute(&mut app, Operation::CommitPrediction {
+                bot_id: "sentiment".to_string(),
+                asset: ASSET.to_string(),
+                commitment: prediction("secret").hash(),
+                horizon_ms: "1000".to_string(),
+            }),
+            Err(BotStateError::Unauthorized { role: "operator".to_string() })
+        );
 
-        assert_eq!(*app.state.follower_count.get(), 1);
+        let sentiment = app.state.bots.load_entry_mut("sentiment")
+            .blocking_wait()
+            .expect("Failed to load bot");
+        assert_eq!(*sentiment.next_signal_id.get(), 0);
+        // The resolver defaults to the admin who created the bot
+        assert_eq!(*sentiment.resolver.get(), Some(owner("operator")));
+        assert_eq!(*bot(&mut app).next_signal_id.get(), 1);
+
+        let mut bot_ids = app.state.bot_ids()
+            .blocking_wait()
+            .expect("Failed to read bots");
+        bot_ids.sort();
+        assert_eq!(bot_ids, vec!["sentiment".to_string(), BOT_ID.to_string()]);
+    }
+
+    #[test]
+    fn test_create_bot_is_validated() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        let create = |bot_id: &str| Operation::CreateBot {
+            bot_id: bot_id.to_string(),
+            operator: None,
+            resolver: None,
+            stake: Amount::ZERO,
+            slashing_policy: None,
+        };
+
+        assert_eq!(
+            execute(&mut app, create(BOT_ID)),
+            Err(BotStateError::BotAlreadyExists(BOT_ID.to_string()))
+        );
+        assert_eq!(execute(&mut app, create("")), Err(BotStateError::InvalidBotId));
+
+        app.runtime.set_authenticated_signer(Some(owner("mallory")));
+        assert_eq!(
+            execute(&mut app, create("mallory-bot")),
+            Err(BotStateError::Unauthorized { role: "admin".to_string() })
+        );
+        assert_eq!(
+            execute(&mut app, Operation::GetLatestSignal { bot_id: "missing".to_string() }),
+            Err(BotStateError::UnknownBot("missing".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_retired_bot_stops_publishing() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        submit(&mut app, "1000000").expect("Submission should succeed");
+
+        app.runtime.set_authenticated_signer(Some(owner("mallory")));
+        assert_eq!(
+            execute(&mut app, Operation::RetireBot { bot_id: BOT_ID.to_string() }),
+            Err(BotStateError::Unauthorized { role: "admin".to_string() })
+        );
+
+        app.runtime.set_authenticated_signer(Some(owner("operator")));
+        execute(&mut app, Operation::RetireBot { bot_id: BOT_ID.to_string() })
+            .expect("Operator may retire the bot");
+        assert_eq!(
+            submit(&mut app, "2000000"),
+            Err(BotStateError::BotRetired(BOT_ID.to_string()))
+        );
+
+        // Signals published before retirement can still be resolved
+        resolve(&mut app, "1000000").expect("Resolution should succeed");
+    }
+
+    #[test]
+    fn test_retired_bot_stops_reporting_to_registry() {
+        let mut app = create_and_instantiate_app(BOT_ID.to_string());
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let recorded = calls.clone();
+        app.runtime.set_call_application_handler(move |_, _, operation| {
+            let operation = bcs::from_bytes::<RegistryOperation>(&operation)
+                .expect("Registry call should be a registry operation");
+            recorded.borrow_mut().push(operation);
+            bcs::to_bytes(&Ok::<(), RegistryError>(())).expect("Failed to serialize response")
+        });
+        app.state.registry.set(Some(ApplicationId::new(CryptoHash::test_hash("registry"))));
+        add_free_tier(&mut app);
+
+        follow(&mut app, "alice", free_subscription(1));
+        follow(&mut app, "operator", Operation::RetireBot { bot_id: BOT_ID.to_string() });
+        follow(&mut app, "alice", Operation::RemoveFollower { bot_id: BOT_ID.to_string() });
+
+        let calls = calls.borrow();
+        assert_eq!(calls.len(), 2);
+        assert!(matches!(calls[0], RegistryOperation::ReportMetrics { .. }));
+        assert!(matches!(calls[1], RegistryOperation::Unregister { .. }));
+    }
+
+    #[test]
+    fn test_registry_rejections_leave_state_unchanged() {
+        let mut app = create_staked_app(None);
+        app.runtime.set_call_application_handler(|_, _, _| {
+            bcs::to_bytes(&Err::<(), RegistryError>(RegistryError::UnauthorizedApplication))
+                .expect("Failed to serialize response")
+        });
+        app.state.registry.set(Some(ApplicationId::new(CryptoHash::test_hash("registry"))));
+        let rejected = Err(BotStateError::Registry(RegistryError::UnauthorizedApplication));
+
+        let result = execute(&mut app, Operation::CreateBot {
+            bot_id: "sentiment".to_string(),
+            operator: None,
+            resolver: None,
+            stake: Amount::from_tokens(10),
+            slashing_policy: None,
+        });
+        assert_eq!(result, rejected);
+        let created = app.state.bots.try_load_entry("sentiment")
+            .blocking_wait()
+            .expect("Failed to read bots")
+            .is_some();
+        assert!(!created);
+        assert_eq!(app.runtime.owner_balance(owner("operator")), Amount::from_tokens(90));
+
+        assert_eq!(
+            execute(&mut app, Operation::RetireBot { bot_id: BOT_ID.to_string() }),
+            rejected
+        );
+        assert!(!*bot(&mut app).retired.get());
+
+        // Metrics reports are best-effort, so following succeeds without one
+        add_free_tier(&mut app);
+        follow(&mut app, "alice", free_subscription(1));
+        assert_eq!(follower_count(&mut app), 1);
+    }
+
+    #[test]
+    fn test_stake_is_held_until_predictions_settle() {
+        let mut app = create_staked_app(None);
+        let committed_at = commit_and_reveal(&mut app, NOW_MS - 1000, 1000);
+        execute(&mut app, Operation::CommitPrediction {
+            bot_id: BOT_ID.to_string(),
+            asset: ASSET.to_string(),
+            commitment: prediction("later").hash(),
+            horizon_ms: "1000".to_string(),
+        })
+        .expect("Commitment should succeed");
+
+        let release = || Operation::ReleaseStake { bot_id: BOT_ID.to_string() };
+        assert_eq!(
+            execute(&mut app, release()),
+            Err(BotStateError::BotNotRetired(BOT_ID.to_string()))
+        );
+        execute(&mut app, Operation::RetireBot { bot_id: BOT_ID.to_string() })
+            .expect("Operator may retire the bot");
+        assert_eq!(*bot(&mut app).stake.get(), Amount::from_tokens(10));
+
+        // The revealed signal and the open commitment are still outstanding
+        assert_eq!(execute(&mut app, release()), Err(BotStateError::StakeLocked(2)));
+        resolve(&mut app, &committed_at.to_string()).expect("Resolution should succeed");
+        assert_eq!(execute(&mut app, release()), Err(BotStateError::StakeLocked(1)));
+
+        // Once the commitment can no longer be revealed, the stake is returned
+        app.runtime.set_system_time(Timestamp::from((NOW_MS + 1001) * 1000));
+        execute(&mut app, release()).expect("Stake should be released");
+        assert_eq!(*bot(&mut app).stake.get(), Amount::ZERO);
+        assert_eq!(app.runtime.owner_balance(owner("operator")), Amount::from_tokens(100));
+        assert_eq!(app.runtime.owner_balance(application_owner()), Amount::ZERO);
+    }
+
+    #[test]
+    fn test_low_accuracy_resolution_slashes_stake() {
+        let mut app = create_staked_app(Some(slashing_policy(None)));
+        let mut wrong_call = prediction("salt");
+        wrong_call.action = Action::Sell;
+        wrong_call.predicted_price_micro = 2_400_000_000;
+
+        app.runtime.set_system_time(Timestamp::from((NOW_MS - 1000) * 1000));
+        execute(&mut app, Operation::CommitPrediction {
+            bot_id: BOT_ID.to_string(),
+            asset: ASSET.to_string(),
+            commitment: wrong_call.hash(),
+            horizon_ms: "1000".to_string(),
+        })
+        .expect("Commitment should succeed");
+        reveal(&mut app, 0, wrong_call).expect("Reveal should succeed");
+        app.runtime.set_system_time(Timestamp::from(NOW_MS * 1000));
+        assert_eq!(app.runtime.owner_balance(owner("beneficiary")), Amount::ZERO);
+
+        // The price rose, so the Sell call brings accuracy to 0%
+        resolve(&mut app, &(NOW_MS - 1000).to_string()).expect("Resolution should succeed");
+        assert_eq!(app.runtime.owner_balance(owner("beneficiary")), Amount::from_tokens(1));
+        assert_eq!(app.runtime.owner_balance(application_owner()), Amount::from_tokens(9));
+        assert_eq!(*bot(&mut app).stake.get(), Amount::from_tokens(9));
+        let slash = bot(&mut app).slashes.get(0)
+            .blocking_wait()
+            .expect("Failed to read slashes")
+            .expect("Bot should be slashed");
+        assert_eq!(slash.reason, SlashReason::LowAccuracy);
+        assert_eq!(slash.accuracy_bps, Some(0));
+    }
+
+    #[test]
+    fn test_missed_deadline_slashes_stake() {
+        let mut app = create_staked_app(Some(slashing_policy(Some(DAY_MS))));
+
+        // The schedule starts when the bot is created
+        app.runtime.set_system_time(Timestamp::from((NOW_MS + DAY_MS) * 1000));
+        execute(&mut app, Operation::EnforceSchedule { bot_id: BOT_ID.to_string() })
+            .expect("Enforcement should succeed");
+        assert_eq!(app.runtime.owner_balance(owner("beneficiary")), Amount::ZERO);
+
+        app.runtime.set_system_time(Timestamp::from((NOW_MS + DAY_MS + 1) * 1000));
+        execute(&mut app, Operation::EnforceSchedule { bot_id: BOT_ID.to_string() })
+            .expect("Enforcement should succeed");
+        assert_eq!(app.runtime.owner_balance(owner("beneficiary")), Amount::from_tokens(1));
+        assert_eq!(app.runtime.owner_balance(application_owner()), Amount::from_tokens(9));
+        assert_eq!(*bot(&mut app).stake.get(), Amount::from_tokens(9));
+        let slash = bot(&mut app).slashes.get(0)
+            .blocking_wait()
+            .expect("Failed to read slashes")
+            .expect("Bot should be slashed");
+        assert_eq!(slash.reason, SlashReason::MissedSignal);
+        assert_eq!(slash.missed_deadline, Some(NOW_MS + DAY_MS));
+    }
+
+    #[test]
+    fn test_schedule_follows_chain_time() {
+        let mut app = create_staked_app(Some(slashing_policy(Some(DAY_MS))));
+        assert_eq!(
+            submit_with_horizon(&mut app, &u64::MAX.to_string(), 1000),
+            Err(BotStateError::TimestampInFuture {
+                timestamp: u64::MAX,
+                now: NOW_MS,
+                max_drift_ms: MAX_TIMESTAMP_DRIFT_MS,
+            })
+        );
+
+        // A timestamp ahead of the chain's time does not push the next deadline out
+        let ahead = NOW_MS + MAX_TIMESTAMP_DRIFT_MS;
+        submit_with_horizon(&mut app, &ahead.to_string(), 1000)
+            .expect("Submission should succeed");
+        app.runtime.set_system_time(Timestamp::from((NOW_MS + DAY_MS + 1) * 1000));
+        execute(&mut app, Operation::EnforceSchedule { bot_id: BOT_ID.to_string() })
+            .expect("Enforcement should succeed");
+        assert_eq!(app.runtime.owner_balance(owner("beneficiary")), Amount::from_tokens(1));
+    }
+
+    #[test]
+    fn test_violations_are_slashed_once() {
+        let mut app = create_staked_app(Some(slashing_policy(Some(DAY_MS))));
+        let committed_at = commit_and_reveal(&mut app, NOW_MS - 1000, 1000);
+
+        // Enforcing the same missed deadline again does not slash again
+        app.runtime.set_system_time(Timestamp::from((NOW_MS + DAY_MS + 1) * 1000));
+        for _ in 0..2 {
+            execute(&mut app, Operation::EnforceSchedule { bot_id: BOT_ID.to_string() })
+                .expect("Enforcement should succeed");
+        }
+        assert_eq!(app.runtime.owner_balance(owner("beneficiary")), Amount::from_tokens(1));
+        assert_eq!(bot(&mut app).slashes.count(), 1);
+
+        // A resolved signal cannot be resolved, and so scored and slashed, a second time
+        app.runtime.set_system_time(Timestamp::from(NOW_MS * 1000));
+        resolve(&mut app, &committed_at.to_string()).expect("Resolution should succeed");
+        assert_eq!(
+            resolve(&mut app, &committed_at.to_string()),
+            Err(BotStateError::AlreadyResolved(0))
+        );
+        assert_eq!(app.runtime.owner_balance(owner("beneficiary")), Amount::from_tokens(1));
+        assert_eq!(*bot(&mut app).stake.get(), Amount::from_tokens(9));
+    }
+
+    /// Slashes 10% when accuracy is below 50% or no signal came for `max_signal_interval_ms`
+    fn slashing_policy(max_signal_interval_ms: Option<u64>) -> SlashingPolicy {
+        SlashingPolicy {
+            beneficiary: Account {
+                chain_id: chain("bot"),
+                owner: owner("beneficiary"),
+            },
+            slash_bps: 1000,
+            accuracy_window: MetricsWindow::AllTime,
+            min_accuracy_bps: 5000,
+            min_predictions: 1,
+            max_signal_interval_ms,
+        }
+    }
+
+    fn follow(app: &mut BotStateContract, name: &str, operation: Operation) {
+        app.runtime.set_authenticated_signer(Some(owner(name)));
+        execute(app, operation).expect("Follow operations should succeed");
+    }
+
+    fn follower_count(app: &mut BotStateContract) -> u64 {
+        let now = app.runtime.system_time().micros() / 1000;
+        bot(app).follower_count(now)
+            .blocking_wait()
+            .expect("Failed to count followers")
+    }
+
+    /// Bot created at instantiation
+    const BOT_ID: &str = "test-bot";
+
+    fn bot(app: &mut BotStateContract) -> &mut Bot {
+        app.state.bots.load_entry_mut(BOT_ID)
+            .blocking_wait()
+            .expect("Failed to load bot")
+    }
+
+    /// Asset of the signals submitted by the helpers
+    const ASSET: &str = "ETHUSDT";
+
+    /// Mocked system time of the test runtime, in milliseconds
+    const NOW_MS: u64 = 3_000_000;
+
+    const DAY_MS: u64 = 24 * 3_600_000;
+
+    /// The prediction used by commit-reveal tests, hidden with `salt`
+    fn prediction(salt: &str) -> CommittedPrediction {
+        CommittedPrediction {
+            bot_id: BOT_ID.to_string(),
+            chain_id: chain("bot"),
+            asset: ASSET.to_string(),
+            action: Action::Buy,
+            reference_price_micro: 2_450_000_000,
+            predicted_price_micro: 2_500_000_000,
+            confidence_bps: 7500,
+            reasoning: "Test".to_string(),
+            salt: salt.to_string(),
+        }
+    }
+
+    fn reveal(
+        app: &mut BotStateContract,
+        commitment_id: u64,
+        prediction: CommittedPrediction,
+    ) -> Result<BotResponse, BotStateError> {
+        execute(app, Operation::RevealPrediction {
+            bot_id: BOT_ID.to_string(),
+            commitment_id: commitment_id.to_string(),
+            action: prediction.action,
+            reference_price_micro: prediction.reference_price_micro.to_string(),
+            predicted_price_micro: prediction.predicted_price_micro.to_string(),
+            confidence_bps: prediction.confidence_bps,
+            salt: prediction.salt,
+            reasoning: prediction.reasoning,
+        })
+    }
+
+    /// Commit at `committed_at` to a prediction resolving `horizon_ms` later and reveal it
+    /// right away, returning the signal timestamp
+    fn commit_and_reveal(app: &mut BotStateContract, committed_at: u64, horizon_ms: u64) -> u64 {
+        app.runtime.set_system_time(Timestamp::from(committed_at * 1000));
+        let commitment_id = *bot(app).next_commitment_id.get();
+        execute(app, Operation::CommitPrediction {
+            bot_id: BOT_ID.to_string(),
+            asset: ASSET.to_string(),
+            commitment: prediction("salt").hash(),
+            horizon_ms: horizon_ms.to_string(),
+        })
+        .expect("Commitment should succeed");
+        reveal(app, commitment_id, prediction("salt")).expect("Reveal should succeed");
+        app.runtime.set_system_time(Timestamp::from(NOW_MS * 1000));
+        committed_at
+    }
+
+    /// Offer a free daily tier named "free"
+    fn add_free_tier(app: &mut BotStateContract) {
+        app.runtime.set_authenticated_signer(Some(owner("operator")));
+        execute(app, Operation::SetSubscriptionTier {
+            bot_id: BOT_ID.to_string(),
+            name: "free".to_string(),
+            price: Amount::ZERO,
+            period_ms: DAY_MS.to_string(),
+        })
+        .expect("Operator may add a tier");
+    }
+
+    fn get_signal(id: &str) -> Operation {
+        Operation::GetSignal {
+            bot_id: BOT_ID.to_string(),
+            id: id.to_string(),
+        }
+    }
+
+    fn stake(amount: Amount) -> Operation {
+        Operation::Stake {
+            bot_id: BOT_ID.to_string(),
+            amount,
+        }
+    }
+
+    fn free_subscription(periods: u64) -> Operation {
+        Operation::AddFollower {
+            bot_id: BOT_ID.to_string(),
+            tier: "free".to_string(),
+            periods,
+        }
+    }
+
+    fn execute(
+        app: &mut BotStateContract,
+        operation: Operation,
+    ) -> Result<BotResponse, BotStateError> {
+        app.execute_operation(operation)
+            .blocking_wait()
+    }
+
+    /// Submit a prediction whose target timestamp is `NOW_MS`, so it can be resolved right away
+    fn submit(
+        app: &mut BotStateContract,
+        timestamp: &str,
+    ) -> Result<BotResponse, BotStateError> {
+        let horizon_ms = NOW_MS.saturating_sub(timestamp.parse::<u64>().unwrap());
+        submit_with_horizon(app, timestamp, horizon_ms)
+    }
+
+    fn submit_with_horizon(
+        app: &mut BotStateContract,
+        timestamp: &str,
+        horizon_ms: u64,
+    ) -> Result<BotResponse, BotStateError> {
+        execute(app, Operation::SubmitPrediction {
+            bot_id: BOT_ID.to_string(),
+            asset: ASSET.to_string(),
+            timestamp: timestamp.to_string(),
+            horizon_ms: horizon_ms.to_string(),
+            action: Action::Buy,
+            reference_price_micro: "2450000000".to_string(),
+            predicted_price_micro: "2500000000".to_string(),
+            confidence_bps: 7500,
+            reasoning: "Test".to_string(),
+        })
+    }
+
+    fn resolve(
+        app: &mut BotStateContract,
+        timestamp: &str,
+    ) -> Result<BotResponse, BotStateError> {
+        app.runtime.set_authenticated_signer(Some(owner("resolver")));
+        let result = execute(app, Operation::ResolveSignal {
+            bot_id: BOT_ID.to_string(),
+            asset: ASSET.to_string(),
+            timestamp: timestamp.to_string(),
+            actual_price_micro: "2600000000".to_string(),
+        });
+        app.runtime.set_authenticated_signer(Some(owner("operator")));
+        result
+    }
+
+    fn stored_signal(app: &mut BotStateContract, id: u64) -> Signal {
+        bot(app).signals.get(&id)
+            .blocking_wait()
+            .expect("Failed to read signal")
+            .expect("Signal should exist")
+    }
+
+    /// New events `previous_index..next_index` of the bot stream published on `chain_id`
+    fn stream_update(chain_id: ChainId, previous_index: u32, next_index: u32) -> StreamUpdate {
+        let application_id = ApplicationId::new(CryptoHash::test_hash("bot-state"));
+        StreamUpdate {
+            chain_id,
+            stream_id: StreamId {
+                application_id: GenericApplicationId::User(application_id),
+                stream_name: StreamName::from(BOT_STREAM_NAME),
+            },
+            previous_index,
+            next_index,
+        }
+    }
+
+    fn owner(name: &str) -> AccountOwner {
+        AccountOwner::from(CryptoHash::test_hash(name))
+    }
+
+    fn chain(name: &str) -> ChainId {
+        ChainId(CryptoHash::test_hash(name))
+    }
+
+    /// Account owner of the application, which holds the stakes
+    fn application_owner() -> AccountOwner {
+        ApplicationId::new(CryptoHash::test_hash("bot-state")).into()
+    }
+
+    /// Create an app whose bot is staked with 10 of the operator's 100 tokens
+    fn create_staked_app(slashing_policy: Option<SlashingPolicy>) -> BotStateContract {
+        let application_id =
+            ApplicationId::new(CryptoHash::test_hash("bot-state")).with_abi::<BotStateAbi>();
+        let runtime = ContractRuntime::new()
+            .with_application_parameters(BotParameters::default())
+            .with_application_id(application_id)
+            .with_owner_balances([
+                (owner("operator"), Amount::from_tokens(100)),
+                (owner("beneficiary"), Amount::ZERO),
+                (application_owner(), Amount::ZERO),
+            ])
+            .with_system_time(Timestamp::from(NOW_MS * 1000))
+            .with_authenticated_signer(owner("operator"))
+            .with_chain_id(chain("bot"))
+            .with_application_creator_chain_id(chain("bot"));
+        let mut contract = BotStateContract {
+            state: BotState::load(runtime.root_view_storage_context())
+                .blocking_wait()
+                .expect("Failed to read from mock key value store"),
+            runtime,
+        };
+
+        contract
+            .instantiate(InstantiationArgument {
+                bot_id: BOT_ID.to_string(),
+                operator: None,
+                resolver: Some(owner("resolver")),
+                registry: None,
+                stake: Amount::from_tokens(10),
+                slashing_policy,
+            })
+            .blocking_wait();
+
+        contract
     }
 
     fn create_and_instantiate_app(bot_id: String) -> BotStateContract {
-        let runtime = ContractRuntime::new().with_application_parameters(());
+        let runtime = ContractRuntime::new()
+            .with_application_parameters(BotParameters::default())
+            .with_system_time(Timestamp::from(NOW_MS * 1000))
+            .with_authenticated_signer(owner("operator"))
+            .with_chain_id(chain("bot"))
+            .with_application_creator_chain_id(chain("bot"));
         let mut contract = BotStateContract {
             state: BotState::load(runtime.root_view_storage_context())
                 .blocking_wait()
@@ -196,9 +2269,15 @@ mod tests {
         };
 
         contract
-            .instantiate(bot_id)
-            .now_or_never()
-            .expect("Initialization should not await anything");
+            .instantiate(InstantiationArgument {
+                bot_id,
+                operator: None, // Defaults to the creator, i.e. owner("operator")
+                resolver: Some(owner("resolver")),
+                registry: None,
+                stake: Amount::ZERO,
+                slashing_policy: None,
+            })
+            .blocking_wait();
 
         contract
     }
@@ -0,0 +1,146 @@
+use bot_registry::RegistryError;
+use linera_sdk::linera_base_types::Amount;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Errors returned by bot state operations
+#[derive(Debug, Clone, Error, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BotStateError {
+    #[error("Bot id must be non-empty and <= 64 characters")]
+    InvalidBotId,
+
+    #[error("No bot with id {0:?}")]
+    UnknownBot(String),
+
+    #[error("A bot with id {0:?} already exists")]
+    BotAlreadyExists(String),
+
+    #[error("Bot {0:?} is retired")]
+    BotRetired(String),
+
+    #[error("Bot {0:?} must be retired before its stake is released")]
+    BotNotRetired(String),
+
+    #[error("Stake is held until {0} outstanding signals and commitments resolve or expire")]
+    StakeLocked(u64),
+
+    /// A numeric argument passed as string could not be parsed
+    #[error("Invalid {field}: {value:?} is not an unsigned integer")]
+    InvalidNumber { field: String, value: String },
+
+    #[error("Asset must be 1-20 uppercase letters or digits, got {0:?}")]
+    InvalidAsset(String),
+
+    #[error("Asset {0:?} is not among the symbols configured for this application")]
+    UnsupportedAsset(String),
+
+    /// The application parameters failed validation
+    #[error("Invalid application parameter: {0}")]
+    InvalidParameter(String),
+
+    #[error("Confidence must be between 0 and 10000 basis points, got {0}")]
+    ConfidenceOutOfRange(u64),
+
+    #[error("Predicted price must be positive")]
+    ZeroPredictedPrice,
+
+    #[error("Reference price must be positive")]
+    ZeroReferencePrice,
+
+    #[error("Reasoning must be <= 512 characters, got {0}")]
+    ReasoningTooLong(usize),
+
+    #[error("Timestamp must be non-zero")]
+    ZeroTimestamp,
+
+    #[error("Timestamp {timestamp} is more than {max_drift_ms}ms ahead of the chain time {now}")]
+    TimestampInFuture {
+        timestamp: u64,
+        now: u64,
+        max_drift_ms: u64,
+    },
+
+    #[error("Target timestamp must be after the signal timestamp")]
+    TargetNotAfterTimestamp,
+
+    #[error("A signal with timestamp {0} already exists")]
+    DuplicateTimestamp(u64),
+
+    #[error("Signal timestamp {timestamp} must be greater than previous signal timestamp {latest}")]
+    TimestampNotIncreasing { timestamp: u64, latest: u64 },
+
+    #[error("No signal with timestamp {0}")]
+    UnknownSignal(u64),
+
+    #[error("Signal {0} is already resolved")]
+    AlreadyResolved(u64),
+
+    #[error("Signal {id} can only be resolved within {grace_ms}ms of its target timestamp {target_timestamp}")]
+    OutsideResolutionWindow {
+        id: u64,
+        target_timestamp: u64,
+        grace_ms: u64,
+    },
+
+    #[error("Subscriptions must be made from a follower chain, not the bot chain")]
+    SubscribeFromBotChain,
+
+    #[error("Operation requires an authenticated signer")]
+    MissingSigner,
+
+    #[error("Unauthorized: operation must be signed by the bot {role}")]
+    Unauthorized { role: String },
+
+    #[error("Stake amount must be positive")]
+    ZeroStake,
+
+    #[error("Slash must be between 0 and 10000 basis points, got {0}")]
+    SlashOutOfRange(u64),
+
+    #[error("Accuracy threshold must be between 0 and 10000 basis points, got {0}")]
+    AccuracyThresholdOutOfRange(u64),
+
+    #[error("Signal interval must be positive")]
+    ZeroSignalInterval,
+
+    #[error("Subscription tier name must be non-empty and <= 32 characters")]
+    InvalidTierName,
+
+    #[error("Subscription period must be positive")]
+    ZeroTierPeriod,
+
+    #[error("No subscription tier named {0:?}")]
+    UnknownTier(String),
+
+    #[error("Subscriptions must be paid for at least one period")]
+    ZeroPeriods,
+
+    #[error("No commitment with id {0}")]
+    UnknownCommitment(u64),
+
+    #[error("Commitment {0} is already revealed")]
+    AlreadyRevealed(u64),
+
+    #[error("Commitment {id} had to be revealed before its target timestamp {target_timestamp}")]
+    RevealTooLate { id: u64, target_timestamp: u64 },
+
+    #[error("Revealed prediction does not match commitment {0}")]
+    CommitmentMismatch(u64),
+
+    /// The bot registry refused a registration or removal
+    #[error("Bot registry rejected the call: {0}")]
+    Registry(RegistryError),
+
+    #[error("Cannot withdraw {requested}: only {available} of subscription revenue is available")]
+    InsufficientRevenue { requested: Amount, available: Amount },
+}
+
+impl BotStateError {
+    /// Parse a u64 argument passed as string (to avoid GraphQL Int32 overflow)
+    pub fn parse_u64(field: &str, value: &str) -> Result<u64, Self> {
+        value.parse::<u64>().map_err(|_| BotStateError::InvalidNumber {
+            field: field.to_string(),
+            value: value.to_string(),
+        })
+    }
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+use crate::state::{AccuracyMetrics, Commitment, Signal};
+
+/// Name of the event stream the bot chain publishes to
+pub const BOT_STREAM_NAME: &[u8] = b"bot_events";
+
+/// Events emitted by the bot chain on `BOT_STREAM_NAME`, for every hosted bot
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum BotEvent {
+    /// A new signal was accepted
+    SignalSubmitted { bot_id: String, signal: Signal },
+
+    /// A signal was resolved with the actual price
+    SignalResolved { bot_id: String, signal: Signal },
+
+    /// Metrics for every window after a resolution (ring buffers omitted)
+    MetricsUpdated {
+        bot_id: String,
+        metrics: Vec<AccuracyMetrics>,
+    },
+
+    /// A prediction was committed; its signal follows when it is revealed
+    PredictionCommitted {
+        bot_id: String,
+        commitment: Commitment,
+    },
+}
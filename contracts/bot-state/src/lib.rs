@@ -1,21 +1,65 @@
+mod error;
+mod event;
+mod message;
 mod operation;
+mod parameters;
+mod response;
 mod state;
 
+pub use error::BotStateError;
+pub use event::{BotEvent, BOT_STREAM_NAME};
+pub use message::Message;
 pub use operation::Operation;
-pub use state::{AccuracyMetrics, Action, BotState, Signal};
+pub use parameters::{
+    BotParameters, EnsembleParameters, TechnicalParameters, KLINE_INTERVALS, LLM_STRATEGIES,
+    MAX_KLINE_LIMIT, TECHNICAL_STRATEGY,
+};
+pub use response::BotResponse;
+pub use state::{
+    AccuracyMetrics, Action, Bot, BotState, Commitment, CommittedPrediction, ExpiryKey,
+    Follower, MetricsBucket, MetricsWindow, Signal, Slash, SlashReason, SlashingPolicy,
+    SubscriptionTier, DEFAULT_HOLD_BAND_BPS, MAX_ASSET_LEN, MAX_TIMESTAMP_DRIFT_MS, RESOLUTION_GRACE_MS,
+};
 
 use async_graphql::{Request, Response};
-use linera_sdk::linera_base_types::{ContractAbi, ServiceAbi};
+use linera_sdk::linera_base_types::{
+    AccountOwner, Amount, ApplicationId, ContractAbi, ServiceAbi,
+};
+use serde::{Deserialize, Serialize};
 
 /// ABI for the BotState application
 pub struct BotStateAbi;
 
 impl ContractAbi for BotStateAbi {
     type Operation = Operation;
-    type Response = ();
+    type Response = Result<BotResponse, BotStateError>;
 }
 
 impl ServiceAbi for BotStateAbi {
     type Query = Request;
     type QueryResponse = Response;
 }
+
+/// Arguments used to create the application along with its first bot
+///
+/// The creator becomes the admin, who can host more bots with `Operation::CreateBot`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstantiationArgument {
+    /// Unique identifier for the first bot (e.g., "momentum", "sentiment")
+    pub bot_id: String,
+    /// Account allowed to submit predictions and manage roles (defaults to the creator)
+    #[serde(default)]
+    pub operator: Option<AccountOwner>,
+    /// Account allowed to resolve predictions with actual prices (defaults to the creator)
+    #[serde(default)]
+    pub resolver: Option<AccountOwner>,
+    /// Bot registry application every bot registers with and reports metrics to, if any
+    #[serde(default)]
+    pub registry: Option<ApplicationId>,
+    /// Native tokens the creator stakes on the first bot
+    #[serde(default)]
+    pub stake: Amount,
+    /// Rules for slashing the first bot's stake (no slashing if unset)
+    #[serde(default)]
+    pub slashing_policy: Option<SlashingPolicy>,
+}
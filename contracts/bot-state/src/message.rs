@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use crate::state::Signal;
+
+/// Cross-chain messages between the bot chain and its follower chains
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Message {
+    /// Sent by a follower chain to the bot chain to start receiving a bot's signals
+    Subscribe { bot_id: String },
+
+    /// Sent by a follower chain to the bot chain to stop receiving a bot's signals
+    Unsubscribe { bot_id: String },
+
+    /// Sent by the bot chain to every subscriber when a signal is submitted
+    SignalPublished { bot_id: String, signal: Signal },
+
+    /// Sent by the bot chain to every subscriber when a signal is resolved
+    SignalResolved { bot_id: String, signal: Signal },
+}
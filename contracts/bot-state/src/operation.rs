@@ -1,15 +1,65 @@
-use crate::state::Action;
-use linera_sdk::graphql::GraphQLMutationRoot;
+// The generated GraphQL mutations take one argument per operation field
+#![allow(clippy::too_many_arguments)]
+
+use crate::state::{Action, MetricsWindow, SlashingPolicy};
+use linera_sdk::{
+    graphql::GraphQLMutationRoot,
+    linera_base_types::{Account, AccountOwner, Amount, CryptoHash},
+};
 use serde::{Deserialize, Serialize};
 
 /// Operations that can be performed on the bot state
+///
+/// Every operation except event subscriptions targets one hosted bot by `bot_id`.
 #[derive(Debug, Serialize, Deserialize, GraphQLMutationRoot)]
 pub enum Operation {
-    /// Submit a new prediction signal
+    /// Host a new bot in this application (admin only)
+    CreateBot {
+        bot_id: String,
+        /// Account allowed to submit predictions and manage roles (defaults to the signer)
+        operator: Option<AccountOwner>,
+        /// Account allowed to resolve predictions (defaults to the signer)
+        resolver: Option<AccountOwner>,
+        /// Native tokens the signer stakes on the bot
+        stake: Amount,
+        /// Rules for slashing the stake (no slashing if unset)
+        slashing_policy: Option<SlashingPolicy>,
+    },
+
+    /// Stop a bot from publishing (admin or operator)
+    ///
+    /// History, pending resolutions and revenue withdrawal remain available. The stake
+    /// stays at risk until it is released with `ReleaseStake`.
+    RetireBot {
+        bot_id: String,
+    },
+
+    /// Return what is left of a retired bot's stake to its operator (admin or operator)
+    ///
+    /// Only accepted once every pending signal is resolved or past its resolution window
+    /// and every commitment is revealed or past its target timestamp, so the stake can no
+    /// longer be slashed for them.
+    ReleaseStake {
+        bot_id: String,
+    },
+
+    /// Submit a new prediction signal in cleartext (operator only)
+    ///
+    /// The timestamp is supplied by the client, so these signals are not scored;
+    /// use `CommitPrediction` and `RevealPrediction` for scored signals.
     SubmitPrediction {
-        /// Unix timestamp in milliseconds as string (to avoid GraphQL Int32 overflow)
+        bot_id: String,
+        /// Trading pair the prediction is about (e.g., "ETHUSDT")
+        asset: String,
+        /// Unix timestamp in milliseconds as string (to avoid GraphQL Int32 overflow),
+        /// increasing with every signal for the same asset and at most 5 minutes ahead of
+        /// the chain's time
         timestamp: String,
+        /// Milliseconds after `timestamp` at which the signal should be resolved, as string
+        horizon_ms: String,
         action: Action,
+        /// Market price in micro-USD when the prediction was made, as string
+        reference_price_micro: String,
         /// Predicted price in micro-USD as string (multiply USD by 1_000_000)
         predicted_price_micro: String,
         /// Confidence in basis points (0-10000, where 10000 = 100%)
@@ -17,17 +67,152 @@ pub enum Operation {
         reasoning: String,
     },
 
-    /// Resolve a previous prediction with actual price
+    /// Commit to a prediction without revealing it (operator only)
+    ///
+    /// The chain's own time is used as the signal timestamp when it is revealed. A
+    /// commitment that is not revealed before its target timestamp counts as a wrong call.
+    CommitPrediction {
+        bot_id: String,
+        /// Trading pair the prediction is about, which must match the committed one
+        asset: String,
+        /// Hash of the `CommittedPrediction` (see `CommittedPrediction::hash`)
+        commitment: CryptoHash,
+        /// Milliseconds after the commitment at which the signal should be resolved, as string
+        horizon_ms: String,
+    },
+
+    /// Reveal a committed prediction before its target timestamp (operator only)
+    ///
+    /// Creates a signal for the committed asset that is scored in the accuracy metrics
+    /// when resolved.
+    RevealPrediction {
+        bot_id: String,
+        /// Commitment id as string
+        commitment_id: String,
+        action: Action,
+        /// Market price in micro-USD at commitment time, as string
+        reference_price_micro: String,
+        /// Predicted price in micro-USD as string
+        predicted_price_micro: String,
+        /// Confidence in basis points (0-10000, where 10000 = 100%)
+        confidence_bps: u64,
+        /// Salt used when computing the commitment
+        salt: String,
+        /// Reasoning included in the commitment
+        reasoning: String,
+    },
+
+    /// Resolve a previous prediction with actual price (resolver only)
+    ///
+    /// Only accepted within the grace window around the signal's target timestamp
     ResolveSignal {
-        /// Unix timestamp in milliseconds as string
+        bot_id: String,
+        /// Trading pair of the signal
+        asset: String,
+        /// Unix timestamp in milliseconds of the signal as string
         timestamp: String,
         /// Actual price in micro-USD as string
-        actual_price_micro: String
+        actual_price_micro: String,
+    },
+
+    /// Hand the operator role over to another account (operator only)
+    TransferOperator {
+        bot_id: String,
+        new_operator: AccountOwner,
+    },
+
+    /// Replace the account allowed to resolve predictions (operator only)
+    SetResolver {
+        bot_id: String,
+        new_resolver: AccountOwner,
+    },
+
+    /// Subscribe the current chain to a bot's signals (executed on a follower chain)
+    Subscribe {
+        bot_id: String,
     },
 
-    /// Increment follower count (called when user follows)
-    AddFollower,
+    /// Unsubscribe the current chain from a bot's signals (executed on a follower chain)
+    Unsubscribe {
+        bot_id: String,
+    },
+
+    /// Subscribe the current chain to the bot chain's event stream, which carries the
+    /// events of every hosted bot (executed on a follower chain)
+    SubscribeToEvents,
+
+    /// Unsubscribe the current chain from the bot chain's event stream
+    UnsubscribeFromEvents,
+
+    /// Subscribe the signing account to a tier for `periods` periods, paying the
+    /// tier price into the application account
+    ///
+    /// An active subscription is extended from its current expiry.
+    AddFollower {
+        bot_id: String,
+        tier: String,
+        periods: u64,
+    },
+
+    /// Unfollow the bot as the signing account, without refund (no-op if not following)
+    RemoveFollower {
+        bot_id: String,
+    },
+
+    /// Create or update a subscription tier (operator only)
+    ///
+    /// Existing subscriptions keep the expiry they paid for.
+    SetSubscriptionTier {
+        bot_id: String,
+        name: String,
+        /// Price of one period in native tokens
+        price: Amount,
+        /// Length of one period in milliseconds as string
+        period_ms: String,
+    },
+
+    /// Stop offering a subscription tier (operator only)
+    RemoveSubscriptionTier {
+        bot_id: String,
+        name: String,
+    },
 
-    /// Decrement follower count (called when user unfollows)
-    RemoveFollower,
+    /// Send subscription revenue held for the bot to `recipient` (operator only)
+    WithdrawRevenue {
+        bot_id: String,
+        amount: Amount,
+        recipient: Account,
+    },
+
+    /// Add native tokens from the signer's account to the operator stake (operator only)
+    Stake {
+        bot_id: String,
+        amount: Amount,
+    },
+
+    /// Slash the stake if the signal schedule was missed and score commitments that were
+    /// not revealed in time (anyone may call)
+    EnforceSchedule {
+        bot_id: String,
+    },
+
+    /// Read the most recently submitted signal (returns `BotResponse::Signal`)
+    GetLatestSignal {
+        bot_id: String,
+    },
+
+    /// Read a signal by its sequential id (returns `BotResponse::Signal`)
+    GetSignal {
+        bot_id: String,
+        /// Signal id as string
+        id: String,
+    },
+
+    /// Read the current accuracy metrics for a window (returns `BotResponse::Metrics`)
+    GetMetrics {
+        bot_id: String,
+        window: MetricsWindow,
+        /// Only count signals for this trading pair (every asset if unset)
+        asset: Option<String>,
+    },
 }
@@ -0,0 +1,236 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::BotStateError;
+use crate::state::{Signal, DEFAULT_HOLD_BAND_BPS, RESOLUTION_GRACE_MS};
+
+/// Kline intervals accepted by Binance
+pub const KLINE_INTERVALS: [&str; 15] = [
+    "1m", "3m", "5m", "15m", "30m", "1h", "2h", "4h", "6h", "8h", "12h", "1d", "3d", "1w", "1M",
+];
+
+/// Maximum number of klines Binance returns per request
+pub const MAX_KLINE_LIMIT: u32 = 1000;
+
+/// Names of the LLM-backed strategies
+pub const LLM_STRATEGIES: [&str; 4] = ["gemma", "deepseek", "qwen-vertex", "gpt-oss-vertex"];
+
+/// Name of the deterministic indicator strategy
+pub const TECHNICAL_STRATEGY: &str = "technical";
+
+/// Deployment settings shared by the contract and the service
+///
+/// Every field is optional in the JSON parameters and falls back to its default.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct BotParameters {
+    /// Base URL of the HTTP proxy forwarding Binance and inference requests
+    pub proxy_url: String,
+
+    /// Trading pairs the bots may publish signals for; the first one is predicted when
+    /// `executePrediction` is called without a symbol
+    pub symbols: Vec<String>,
+
+    /// Binance kline interval of the price history shown to strategies (e.g., "1h")
+    pub kline_interval: String,
+
+    /// Number of klines in the price history
+    pub kline_limit: u32,
+
+    /// Model requested from inference.net by the "gemma" strategy
+    pub model_id: String,
+
+    /// Largest move from the reference price, in basis points, for which a Hold
+    /// signal counts as correct
+    pub hold_band_bps: u64,
+
+    /// How far from its target timestamp a signal may be resolved, in milliseconds
+    pub resolution_grace_ms: u64,
+
+    /// Indicator settings of the "technical" strategy
+    pub technical: TechnicalParameters,
+
+    /// Sub-strategies of the "ensemble" strategy
+    pub ensemble: EnsembleParameters,
+}
+
+impl Default for BotParameters {
+    fn default() -> Self {
+        Self {
+            proxy_url: "http://localhost:3002".to_string(),
+            symbols: vec!["ETHUSDT".to_string(), "BTCUSDT".to_string(), "SOLUSDT".to_string()],
+            kline_interval: "1h".to_string(),
+            kline_limit: 200,
+            model_id: "google/gemma-3-27b-instruct/bf-16".to_string(),
+            hold_band_bps: DEFAULT_HOLD_BAND_BPS,
+            resolution_grace_ms: RESOLUTION_GRACE_MS,
+            technical: TechnicalParameters::default(),
+            ensemble: EnsembleParameters::default(),
+        }
+    }
+}
+
+impl BotParameters {
+    /// Validate parameter constraints
+    pub fn validate(&self) -> Result<(), BotStateError> {
+        let invalid = |reason: &str| Err(BotStateError::InvalidParameter(reason.to_string()));
+
+        if !self.proxy_url.starts_with("http://") && !self.proxy_url.starts_with("https://") {
+            return invalid("proxy_url must be an http(s) URL");
+        }
+        if self.symbols.is_empty() {
+            return invalid("symbols must not be empty");
+        }
+        for symbol in &self.symbols {
+            Signal::validate_asset(symbol)?;
+        }
+        if !KLINE_INTERVALS.contains(&self.kline_interval.as_str()) {
+            return invalid("kline_interval is not a Binance interval");
+        }
+        if self.kline_limit == 0 || self.kline_limit > MAX_KLINE_LIMIT {
+            return invalid("kline_limit must be between 1 and 1000");
+        }
+        if self.model_id.is_empty() {
+            return invalid("model_id must not be empty");
+        }
+        if self.hold_band_bps > 10_000 {
+            return invalid("hold_band_bps must be at most 10000");
+        }
+        if self.resolution_grace_ms == 0 {
+            return invalid("resolution_grace_ms must be positive");
+        }
+        self.technical.validate()?;
+        self.ensemble.validate()?;
+        if self.technical.required_history() > self.kline_limit as usize {
+            return invalid("kline_limit is too small for the technical indicator periods");
+        }
+        Ok(())
+    }
+
+    /// Check whether bots may publish signals for `asset`
+    pub fn supports(&self, asset: &str) -> bool {
+        self.symbols.iter().any(|symbol| symbol == asset)
+    }
+
+    /// Trading pair predicted when none is requested (parameters must be valid)
+    pub fn default_symbol(&self) -> &str {
+        &self.symbols[0]
+    }
+}
+
+/// Indicator periods and thresholds of the deterministic "technical" strategy
+///
+/// Each indicator votes bullish, bearish or neutral: the fast SMA against the slow one,
+/// the MACD line against its signal line, and the RSI against its thresholds (an
+/// overbought market votes bearish, an oversold one bullish).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct TechnicalParameters {
+    /// Candles in the fast simple moving average
+    pub sma_fast_period: u32,
+    /// Candles in the slow simple moving average
+    pub sma_slow_period: u32,
+    /// Candles in the fast EMA of the MACD line
+    pub macd_fast_period: u32,
+    /// Candles in the slow EMA of the MACD line
+    pub macd_slow_period: u32,
+    /// Candles in the EMA of the MACD line forming its signal line
+    pub macd_signal_period: u32,
+    /// Candles in the relative strength index
+    pub rsi_period: u32,
+    /// RSI at or above which the market is overbought (0-100)
+    pub rsi_overbought: u32,
+    /// RSI at or below which the market is oversold (0-100)
+    pub rsi_oversold: u32,
+    /// Net votes needed for a Buy or Sell signal instead of Hold
+    pub min_votes: u32,
+}
+
+impl Default for TechnicalParameters {
+    fn default() -> Self {
+        Self {
+            sma_fast_period: 20,
+            sma_slow_period: 50,
+            macd_fast_period: 12,
+            macd_slow_period: 26,
+            macd_signal_period: 9,
+            rsi_period: 14,
+            rsi_overbought: 70,
+            rsi_oversold: 30,
+            min_votes: 2,
+        }
+    }
+}
+
+impl TechnicalParameters {
+    /// Validate parameter constraints
+    pub fn validate(&self) -> Result<(), BotStateError> {
+        let invalid = |reason: &str| Err(BotStateError::InvalidParameter(reason.to_string()));
+
+        if self.sma_fast_period == 0 || self.sma_fast_period >= self.sma_slow_period {
+            return invalid("technical SMA periods must satisfy 0 < fast < slow");
+        }
+        if self.macd_fast_period == 0 || self.macd_fast_period >= self.macd_slow_period {
+            return invalid("technical MACD periods must satisfy 0 < fast < slow");
+        }
+        if self.macd_signal_period == 0 || self.rsi_period == 0 {
+            return invalid("technical MACD signal and RSI periods must be positive");
+        }
+        if self.rsi_oversold >= self.rsi_overbought || self.rsi_overbought > 100 {
+            return invalid("technical RSI thresholds must satisfy oversold < overbought <= 100");
+        }
+        if self.min_votes == 0 || self.min_votes > 3 {
+            return invalid("technical min_votes must be between 1 and 3");
+        }
+        Ok(())
+    }
+
+    /// Number of candles needed to compute every indicator
+    pub fn required_history(&self) -> usize {
+        let sma = self.sma_slow_period;
+        let macd = self.macd_slow_period + self.macd_signal_period - 1;
+        let rsi = self.rsi_period + 1;
+        sma.max(macd).max(rsi) as usize
+    }
+}
+
+/// Sub-strategies whose signals the "ensemble" strategy combines
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct EnsembleParameters {
+    /// Names of the strategies to run on the same market snapshot (e.g., "technical")
+    pub strategies: Vec<String>,
+}
+
+impl Default for EnsembleParameters {
+    fn default() -> Self {
+        Self {
+            strategies: vec!["technical".to_string(), "gemma".to_string()],
+        }
+    }
+}
+
+impl EnsembleParameters {
+    /// Validate parameter constraints
+    pub fn validate(&self) -> Result<(), BotStateError> {
+        let invalid = |reason: &str| Err(BotStateError::InvalidParameter(reason.to_string()));
+
+        if self.strategies.is_empty() {
+            return invalid("ensemble strategies must not be empty");
+        }
+        if self.strategies.iter().any(|name| name == "ensemble") {
+            return invalid("ensemble strategies must not include the ensemble itself");
+        }
+        for (i, name) in self.strategies.iter().enumerate() {
+            if name != TECHNICAL_STRATEGY && !LLM_STRATEGIES.contains(&name.as_str()) {
+                return Err(BotStateError::InvalidParameter(format!(
+                    "unknown ensemble strategy {:?}",
+                    name
+                )));
+            }
+            if self.strategies[..i].contains(name) {
+                return invalid("ensemble strategies must not repeat");
+            }
+        }
+        Ok(())
+    }
+}
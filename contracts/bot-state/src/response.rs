@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use crate::state::{AccuracyMetrics, Signal};
+
+/// Value returned by a successful operation
+///
+/// Other applications on the bot's chain can read its output synchronously, e.g.
+/// `runtime.call_application(false, bot_app_id, &Operation::GetLatestSignal)`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum BotResponse {
+    /// The operation changed state and returns no data
+    Done,
+
+    /// Result of `GetLatestSignal` or `GetSignal` (`None` if there is no such signal)
+    Signal(Option<Signal>),
+
+    /// Result of `GetMetrics`, with the rolling window advanced to the current time
+    Metrics(AccuracyMetrics),
+}
@@ -1,24 +1,33 @@
 #![cfg_attr(target_arch = "wasm32", no_main)]
 
-use std::sync::Arc;
+mod strategy;
 
-use async_graphql::{EmptySubscription, Object, Schema};
+use std::{ops::Deref, sync::Arc};
+
+use async_graphql::{EmptySubscription, MergedObject, Object, Schema, SimpleObject};
 use linera_sdk::{
+    graphql::GraphQLMutationRoot,
     http,
-    linera_base_types::WithServiceAbi,
+    linera_base_types::{
+        AccountOwner, Amount, ApplicationId, ChainId, CryptoHash, WithServiceAbi,
+    },
     views::View,
     Service,
     ServiceRuntime,
 };
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
+
+use bot_state::{
+    AccuracyMetrics, Action, Bot, BotParameters, BotState, Commitment, CommittedPrediction,
+    Follower, MetricsWindow, Operation, Signal, Slash, SlashingPolicy, SubscriptionTier,
+};
 
-use bot_state::{AccuracyMetrics, Action, BotState, Operation, Signal};
+use self::strategy::{MarketSnapshot, PricePoint, StrategyRegistry};
 
 /// Binance 24hr ticker response
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct Binance24hrTicker {
-    symbol: String,
     last_price: String,
     price_change_percent: String,
     quote_volume: String,
@@ -26,7 +35,9 @@ struct Binance24hrTicker {
 
 /// Binance kline (OHLC candlestick) response
 /// Format: [timestamp, open, high, low, close, volume, close_time, quote_volume, trades, taker_buy_base, taker_buy_quote, ignore]
+/// Only the OHLCV fields are read, the rest are kept so the array length matches
 #[derive(Debug, Deserialize)]
+#[allow(dead_code)]
 struct BinanceKline(
     u64,    // Open time
     String, // Open
@@ -42,28 +53,8 @@ struct BinanceKline(
     String, // Ignore
 );
 
-/// Market snapshot for prediction
-#[derive(Debug, Clone, Serialize)]
-struct MarketSnapshot {
-    timestamp: u64,
-    current_price: f64,
-    change_24h: f64,
-    volume_24h: f64,
-    price_history: Vec<PricePoint>,
-}
-
-#[derive(Debug, Clone, Serialize)]
-struct PricePoint {
-    timestamp: u64,
-    open: f64,
-    high: f64,
-    low: f64,
-    close: f64,
-    volume: f64,
-}
-
 pub struct BotStateService {
-    state: BotState,
+    state: Arc<BotState>,
     runtime: Arc<ServiceRuntime<Self>>,
 }
 
@@ -74,34 +65,30 @@ impl WithServiceAbi for BotStateService {
 }
 
 impl Service for BotStateService {
-    type Parameters = ();
+    type Parameters = BotParameters;
 
     async fn new(runtime: ServiceRuntime<Self>) -> Self {
         let state = BotState::load(runtime.root_view_storage_context())
             .await
             .expect("Failed to load state");
         BotStateService {
-            state,
+            state: Arc::new(state),
             runtime: Arc::new(runtime),
         }
     }
 
     async fn handle_query(&self, query: Self::Query) -> Self::QueryResponse {
-        let bot_id = self.state.bot_id.get().clone();
-        let latest_signal = self.state.latest_signal.get().clone();
-        let accuracy_24h = self.state.accuracy_24h.get().clone();
-        let follower_count = *self.state.follower_count.get();
-
         Schema::build(
             BotQueryRoot {
-                bot_id,
-                latest_signal,
-                accuracy_24h,
-                follower_count,
-            },
-            BotMutationRoot {
+                state: self.state.clone(),
                 runtime: self.runtime.clone(),
             },
+            MutationRoot(
+                BotMutationRoot {
+                    runtime: self.runtime.clone(),
+                },
+                Operation::mutation_root(self.runtime.clone()),
+            ),
             EmptySubscription,
         )
         .finish()
@@ -111,11 +98,52 @@ impl Service for BotStateService {
 }
 
 impl BotStateService {
-    /// Fetch current market data from Binance
-    fn fetch_market_data(&self) -> Result<MarketSnapshot, String> {
+    /// Run `strategy` on current market data for `symbol` (default: the first configured
+    /// symbol), targeting `horizon_ms` ahead (default 1 hour)
+    fn predict(
+        &self,
+        strategy: &str,
+        symbol: Option<String>,
+        api_key: Option<String>,
+        horizon_ms: Option<String>,
+    ) -> Result<Signal, String> {
+        let horizon_ms = match horizon_ms {
+            Some(horizon) => horizon.parse::<u64>()
+                .map_err(|e| format!("Invalid horizon: {}", e))?,
+            None => DEFAULT_HORIZON_MS,
+        };
+        if !(MIN_HORIZON_MS..=MAX_HORIZON_MS).contains(&horizon_ms) {
+            return Err(format!(
+                "Invalid horizon: {} ms is not between {} and {} ms",
+                horizon_ms, MIN_HORIZON_MS, MAX_HORIZON_MS
+            ));
+        }
+        let parameters = self.runtime.application_parameters();
+        let symbol = symbol.unwrap_or_else(|| parameters.default_symbol().to_string());
+        if !parameters.supports(&symbol) {
+            return Err(format!("Unsupported symbol: {}", symbol));
+        }
+
+        // Fetch market data
+        let market_data = self.fetch_market_data(&symbol, horizon_ms)?;
+
+        // Route to appropriate strategy
+        let strategies =
+            StrategyRegistry::with_defaults(&parameters, self.runtime.clone(), api_key);
+        strategies
+            .get(strategy)
+            .ok_or_else(|| format!("Unknown strategy: {}", strategy))?
+            .predict(&market_data)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Fetch current market data for `symbol` from Binance for a prediction `horizon_ms` ahead
+    fn fetch_market_data(&self, symbol: &str, horizon_ms: u64) -> Result<MarketSnapshot, String> {
+        let parameters = self.runtime.application_parameters();
+
         // Get 24hr ticker for current price, volume, and change
         // Using localhost proxy to bypass HTTP authorization restrictions
-        let ticker_request = http::Request::get("http://localhost:3002/binance/ticker?symbol=ETHUSDT");
+        let ticker_request = http::Request::get(format!("{}/binance/ticker?symbol={}", parameters.proxy_url, symbol));
         let ticker_response = self.runtime.http_request(ticker_request);
 
         if ticker_response.status != 200 {
@@ -132,9 +160,12 @@ impl BotStateService {
         let volume_24h: f64 = ticker.quote_volume.parse()
             .map_err(|e| format!("Failed to parse volume: {}", e))?;
 
-        // Get 200 hourly candles for price history
+        // Get candles for price history (200 hourly candles by default)
         // Using localhost proxy to bypass HTTP authorization restrictions
-        let klines_request = http::Request::get("http://localhost:3002/binance/klines?symbol=ETHUSDT&interval=1h&limit=200");
+        let klines_request = http::Request::get(format!(
+            "{}/binance/klines?symbol={}&interval={}&limit={}",
+            parameters.proxy_url, symbol, parameters.kline_interval, parameters.kline_limit
+        ));
         let klines_response = self.runtime.http_request(klines_request);
 
         if klines_response.status != 200 {
@@ -158,172 +189,386 @@ impl BotStateService {
             .collect();
 
         Ok(MarketSnapshot {
-            timestamp: self.runtime.system_time().micros(),
+            symbol: symbol.to_string(),
+            timestamp: self.runtime.system_time().micros() / 1000, // Convert to ms
+            horizon_ms,
+            interval: parameters.kline_interval,
             current_price,
             change_24h,
             volume_24h,
             price_history,
         })
     }
+}
 
-    /// Call inference.net API for LLM prediction (using Gemma 3 27B)
-    /// API key is passed from service.rs and forwarded by the proxy
-    fn call_inference_net(&self, market_data: &MarketSnapshot, api_key: &str) -> Result<Signal, String> {
-        let system_prompt = "You are an expert cryptocurrency trader specializing in ETH price predictions.\nAnalyze market data using technical analysis and market psychology to provide clear trading signals.\nIMPORTANT: After your analysis, you MUST provide your final answer in the exact format specified.";
+/// Default prediction horizon (1 hour in milliseconds)
+const DEFAULT_HORIZON_MS: u64 = 3_600_000;
 
-        let user_prompt = self.build_prompt(market_data);
+/// Shortest prediction horizon (1 minute, the finest unit described to LLMs)
+const MIN_HORIZON_MS: u64 = 60_000;
 
-        let body = serde_json::json!({
-            "model": "google/gemma-3-27b-instruct/bf-16",
-            "messages": [
-                {"role": "system", "content": system_prompt},
-                {"role": "user", "content": user_prompt}
-            ],
-            "temperature": 0.7,
-            "max_tokens": 1024
-        });
+/// Longest prediction horizon (30 days)
+const MAX_HORIZON_MS: u64 = 30 * 24 * 3_600_000;
 
-        // Using localhost proxy to bypass HTTP authorization restrictions
-        // Proxy transparently forwards the Authorization header to inference.net
-        let request = http::Request::post(
-            "http://localhost:3002/inference/chat/completions",
-            serde_json::to_vec(&body).map_err(|e| format!("Failed to serialize request: {}", e))?
-        )
-        .with_header("Content-Type", b"application/json")
-        .with_header("Authorization", format!("Bearer {}", api_key).as_bytes());
+/// Default number of signals returned per page
+const DEFAULT_PAGE_SIZE: u32 = 20;
 
-        let response = self.runtime.http_request(request);
+/// Maximum number of signals returned per page
+const MAX_PAGE_SIZE: u32 = 100;
 
-        if response.status != 200 {
-            return Err(format!("Inference.net API error: {} - {}", response.status, String::from_utf8_lossy(&response.body)));
-        }
+/// A page of signal history, newest first
+#[derive(SimpleObject)]
+struct SignalPage {
+    signals: Vec<Signal>,
+    /// Cursor to pass to the next query, or null when the history is exhausted
+    next_cursor: Option<String>,
+}
 
-        let result: serde_json::Value = serde_json::from_slice(&response.body)
-            .map_err(|e| format!("Failed to parse response: {}", e))?;
-
-        let content = result["choices"][0]["message"]["content"]
-            .as_str()
-            .ok_or_else(|| "No content in response".to_string())?;
-
-        self.parse_llm_response(content, market_data)
-    }
-
-    /// Build prompt with market data
-    fn build_prompt(&self, data: &MarketSnapshot) -> String {
-        let ohlc: Vec<String> = data.price_history.iter().enumerate().map(|(i, candle)| {
-            let hours_ago = data.price_history.len() - i;
-            format!(
-                "{}h ago: O=${:.2} H=${:.2} L=${:.2} C=${:.2} V={:.1}k",
-                hours_ago, candle.open, candle.high, candle.low, candle.close, candle.volume / 1000.0
-            )
-        }).collect();
-
-        format!(
-            r#"Current ETH Market Data ({} hourly candles):
-- Current Price: ${:.2}
-- 24h Change: {:.2}%
-- 24h Volume: ${}
-
-Complete OHLC Candlesticks:
-{}
-
-Task: Predict ETH price movement in the next hour based on technical analysis.
-
-At the END of your response, provide your final answer in this EXACT format:
-ACTION: [BUY, SELL, or HOLD]
-PRICE: [predicted price in USD, e.g., 3575.50]
-CONFIDENCE: [0-100, e.g., 75]
-REASONING: [max 200 chars explaining your technical analysis]"#,
-            data.price_history.len(),
-            data.current_price,
-            data.change_24h,
-            (data.volume_24h as u64).to_string(),
-            ohlc.join("\n")
-        )
+/// Parse an optional u64 query argument passed as a string
+fn parse_u64_arg(name: &str, value: Option<String>) -> async_graphql::Result<Option<u64>> {
+    value
+        .map(|v| v.parse::<u64>().map_err(|e| format!("Invalid {}: {}", name, e).into()))
+        .transpose()
+}
+
+/// GraphQL query root for bot state
+struct BotQueryRoot {
+    state: Arc<BotState>,
+    runtime: Arc<ServiceRuntime<BotStateService>>,
+}
+
+#[Object]
+impl BotQueryRoot {
+    /// Get the ids of every bot hosted by this application
+    async fn bots(&self) -> async_graphql::Result<Vec<String>> {
+        Ok(self.state.bot_ids().await?)
     }
 
-    /// Parse LLM response into Signal
-    fn parse_llm_response(&self, content: &str, data: &MarketSnapshot) -> Result<Signal, String> {
-        let mut action = Action::Hold;
-        let mut predicted_price = data.current_price;
-        let mut confidence = 50; // 50% default
-        let mut reasoning = "No reasoning provided".to_string();
-
-        for line in content.lines() {
-            let trimmed = line.trim();
-
-            if trimmed.starts_with("ACTION:") {
-                let action_str = trimmed.split(':').nth(1).unwrap_or("").trim().to_uppercase();
-                action = if action_str.contains("BUY") {
-                    Action::Buy
-                } else if action_str.contains("SELL") {
-                    Action::Sell
-                } else {
-                    Action::Hold
-                };
-            } else if trimmed.starts_with("PRICE:") {
-                let price_str = trimmed.split(':').nth(1).unwrap_or("").trim()
-                    .replace("$", "").replace(",", "");
-                if let Ok(price) = price_str.parse::<f64>() {
-                    if price > 0.0 {
-                        predicted_price = price;
-                    }
-                }
-            } else if trimmed.starts_with("CONFIDENCE:") {
-                let conf_str = trimmed.split(':').nth(1).unwrap_or("").trim()
-                    .replace("%", "");
-                if let Ok(conf) = conf_str.parse::<u64>() {
-                    confidence = conf.min(100); // Clamp to 100
-                }
-            } else if trimmed.starts_with("REASONING:") {
-                reasoning = trimmed.split(':').skip(1).collect::<Vec<&str>>().join(":")
-                    .trim().chars().take(512).collect();
+    /// Get the account allowed to create bots
+    async fn admin(&self) -> Option<AccountOwner> {
+        *self.state.admin.get()
+    }
+
+    /// Get the bot registry application the bots report to, if any
+    async fn registry(&self) -> Option<ApplicationId> {
+        *self.state.registry.get()
+    }
+
+    /// Check whether a bot was retired
+    async fn retired(&self, bot_id: String) -> async_graphql::Result<bool> {
+        Ok(*self.bot(&bot_id).await?.retired.get())
+    }
+
+    /// Get the account allowed to submit a bot's predictions
+    async fn operator(&self, bot_id: String) -> async_graphql::Result<Option<AccountOwner>> {
+        Ok(*self.bot(&bot_id).await?.operator.get())
+    }
+
+    /// Get the account allowed to resolve a bot's predictions
+    async fn resolver(&self, bot_id: String) -> async_graphql::Result<Option<AccountOwner>> {
+        Ok(*self.bot(&bot_id).await?.resolver.get())
+    }
+
+    /// Get the native tokens currently staked by the operator
+    async fn stake(&self, bot_id: String) -> async_graphql::Result<Amount> {
+        Ok(*self.bot(&bot_id).await?.stake.get())
+    }
+
+    /// Get the rules under which the stake is slashed, if any
+    async fn slashing_policy(
+        &self,
+        bot_id: String,
+    ) -> async_graphql::Result<Option<SlashingPolicy>> {
+        Ok(self.bot(&bot_id).await?.slashing_policy.get().clone())
+    }
+
+    /// Get every slash applied to the stake, oldest first
+    async fn slashes(&self, bot_id: String) -> async_graphql::Result<Vec<Slash>> {
+        Ok(self.bot(&bot_id).await?.slashes.read(..).await?)
+    }
+
+    /// Get the chains subscribed to a bot's signals (on the bot chain)
+    async fn subscribers(&self, bot_id: String) -> async_graphql::Result<Vec<ChainId>> {
+        Ok(self.bot(&bot_id).await?.subscribers.indices().await?)
+    }
+
+    /// Get a bot's signals received from the bot chain, newest first (on follower chains)
+    async fn received_signals(
+        &self,
+        bot_id: String,
+        limit: Option<u32>,
+    ) -> async_graphql::Result<Vec<Signal>> {
+        let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE) as usize;
+        let Some(received_signals) = self.state.received_signals.try_load_entry(&bot_id).await?
+        else {
+            return Ok(Vec::new());
+        };
+        let mut ids = received_signals.indices().await?;
+        ids.sort_unstable_by(|a, b| b.cmp(a));
+        let mut signals = Vec::new();
+        for id in ids.into_iter().take(limit) {
+            if let Some(signal) = received_signals.get(&id).await? {
+                signals.push(signal);
             }
         }
+        Ok(signals)
+    }
+
+    /// Get the latest prediction signal, for one asset if `asset` is set
+    async fn latest_signal(
+        &self,
+        bot_id: String,
+        asset: Option<String>,
+    ) -> async_graphql::Result<Option<Signal>> {
+        let bot = self.bot(&bot_id).await?;
+        match asset {
+            Some(asset) => Ok(bot.latest_signal_for(&asset).await?),
+            None => Ok(bot.latest_signal().await?),
+        }
+    }
+
+    /// Get the assets a bot has published signals for
+    async fn assets(&self, bot_id: String) -> async_graphql::Result<Vec<String>> {
+        Ok(self.bot(&bot_id).await?.assets().await?)
+    }
+
+    /// Get a single signal by its sequential id
+    async fn signal(&self, bot_id: String, id: String) -> async_graphql::Result<Option<Signal>> {
+        let id = id.parse::<u64>().map_err(|e| format!("Invalid id: {}", e))?;
+        Ok(self.bot(&bot_id).await?.signals.get(&id).await?)
+    }
+
+    /// Get all signals still waiting to be resolved, oldest first
+    async fn pending_signals(&self, bot_id: String) -> async_graphql::Result<Vec<Signal>> {
+        Ok(self.bot(&bot_id).await?.unresolved_signals().await?)
+    }
+
+    /// Get the pending signals whose target timestamp has already passed
+    async fn overdue_signals(&self, bot_id: String) -> async_graphql::Result<Vec<Signal>> {
+        let now = self.runtime.system_time().micros() / 1000; // Convert to ms
+        Ok(self.bot(&bot_id).await?.overdue_signals(now).await?)
+    }
+
+    /// Get a commitment by its sequential id
+    async fn commitment(
+        &self,
+        bot_id: String,
+        id: String,
+    ) -> async_graphql::Result<Option<Commitment>> {
+        let id = id.parse::<u64>().map_err(|e| format!("Invalid id: {}", e))?;
+        Ok(self.bot(&bot_id).await?.commitments.get(&id).await?)
+    }
+
+    /// Get the latest commitment for an asset
+    async fn latest_commitment(
+        &self,
+        bot_id: String,
+        asset: String,
+    ) -> async_graphql::Result<Option<Commitment>> {
+        Ok(self.bot(&bot_id).await?.latest_commitment_for(&asset).await?)
+    }
+
+    /// Run a strategy without scheduling anything, e.g. to commit its signal later
+    ///
+    /// Takes the same arguments as `executePrediction`. Node service mutations only return
+    /// the block hash, so this is how a client learns the signal it must reveal.
+    async fn prediction(
+        &self,
+        strategy: String,
+        symbol: Option<String>,
+        api_key: Option<String>,
+        horizon_ms: Option<String>,
+    ) -> Result<Signal, String> {
+        let service = BotStateService {
+            state: self.state.clone(),
+            runtime: self.runtime.clone(),
+        };
+        service.predict(&strategy, symbol, api_key, horizon_ms)
+    }
+
+    /// Get the commitments that were never revealed before their target timestamp
+    async fn unrevealed_commitments(
+        &self,
+        bot_id: String,
+    ) -> async_graphql::Result<Vec<Commitment>> {
+        let now = self.runtime.system_time().micros() / 1000; // Convert to ms
+        Ok(self.bot(&bot_id).await?.unrevealed_commitments(now).await?)
+    }
 
-        // Convert to contract format (micro-USD and basis points)
-        Ok(Signal {
-            timestamp: data.timestamp,
+    /// Compute the hash to pass to `commitPrediction` for a bot's prediction on this
+    /// chain, its reasoning and a salt
+    #[allow(clippy::too_many_arguments)]
+    async fn commitment_hash(
+        &self,
+        bot_id: String,
+        asset: String,
+        action: Action,
+        reference_price_micro: String,
+        predicted_price_micro: String,
+        confidence_bps: u64,
+        reasoning: String,
+        salt: String,
+    ) -> async_graphql::Result<CryptoHash> {
+        let prediction = CommittedPrediction {
+            bot_id,
+            chain_id: self.runtime.chain_id(),
+            asset,
             action,
-            predicted_price_micro: (predicted_price * 1_000_000.0) as u64,
-            confidence_bps: confidence * 100, // Convert 0-100 to basis points
+            reference_price_micro: reference_price_micro.parse::<u64>()
+                .map_err(|e| format!("Invalid reference price: {}", e))?,
+            predicted_price_micro: predicted_price_micro.parse::<u64>()
+                .map_err(|e| format!("Invalid predicted price: {}", e))?,
+            confidence_bps,
             reasoning,
-            actual_price_micro: None,
+            salt,
+        };
+        Ok(prediction.hash())
+    }
+
+    /// Get a bot's signal history, newest first
+    ///
+    /// - `cursor`: the `nextCursor` of the previous page (omit for the first page)
+    /// - `limit`: page size (default 20, max 100)
+    /// - `fromTimestamp` / `toTimestamp`: inclusive time range in milliseconds, as strings
+    async fn signals(
+        &self,
+        bot_id: String,
+        cursor: Option<String>,
+        limit: Option<u32>,
+        from_timestamp: Option<String>,
+        to_timestamp: Option<String>,
+    ) -> async_graphql::Result<SignalPage> {
+        let cursor = parse_u64_arg("cursor", cursor)?;
+        let from_timestamp = parse_u64_arg("fromTimestamp", from_timestamp)?.unwrap_or(0);
+        let to_timestamp = parse_u64_arg("toTimestamp", to_timestamp)?.unwrap_or(u64::MAX);
+        let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE) as usize;
+
+        // Cursor is exclusive: continue with the signal just before it
+        let bot = self.bot(&bot_id).await?;
+        let mut next_id = cursor
+            .unwrap_or(u64::MAX)
+            .min(*bot.next_signal_id.get());
+        let mut signals = Vec::new();
+
+        while next_id > 0 && signals.len() < limit {
+            next_id -= 1;
+            let Some(signal) = bot.signals.get(&next_id).await? else {
+                continue;
+            };
+            // Timestamps are not ordered by id (a reveal keeps its commit-time timestamp),
+            // so the whole range is filtered
+            if (from_timestamp..=to_timestamp).contains(&signal.timestamp) {
+                signals.push(signal);
+            }
+        }
+
+        Ok(SignalPage {
+            signals,
+            next_cursor: (next_id > 0).then(|| next_id.to_string()),
         })
     }
-}
 
-/// GraphQL query root for bot state
-struct BotQueryRoot {
-    bot_id: String,
-    latest_signal: Option<Signal>,
-    accuracy_24h: AccuracyMetrics,
-    follower_count: u64,
-}
+    /// Get the accuracy metrics for a time window, excluding expired resolutions
+    ///
+    /// The accuracy queries cover every asset unless `asset` is set.
+    async fn accuracy(
+        &self,
+        bot_id: String,
+        window: MetricsWindow,
+        asset: Option<String>,
+    ) -> async_graphql::Result<AccuracyMetrics> {
+        self.current_accuracy(&bot_id, asset.as_deref(), window).await
+    }
 
-#[Object]
-impl BotQueryRoot {
-    /// Get the bot's unique identifier
-    async fn bot_id(&self) -> &String {
-        &self.bot_id
+    /// Get the 24-hour accuracy metrics (shorthand for `accuracy(window: ONE_DAY)`)
+    async fn accuracy_24h(
+        &self,
+        bot_id: String,
+        asset: Option<String>,
+    ) -> async_graphql::Result<AccuracyMetrics> {
+        self.current_accuracy(&bot_id, asset.as_deref(), MetricsWindow::OneDay).await
+    }
+
+    /// Get the accuracy metrics for every time window
+    async fn accuracy_windows(
+        &self,
+        bot_id: String,
+        asset: Option<String>,
+    ) -> async_graphql::Result<Vec<AccuracyMetrics>> {
+        let mut windows = Vec::new();
+        for window in MetricsWindow::ALL {
+            windows.push(self.current_accuracy(&bot_id, asset.as_deref(), window).await?);
+        }
+        Ok(windows)
     }
 
-    /// Get the latest prediction signal
-    async fn latest_signal(&self) -> &Option<Signal> {
-        &self.latest_signal
+    /// Get the number of followers with an active subscription
+    async fn follower_count(&self, bot_id: String) -> async_graphql::Result<u64> {
+        let now = self.runtime.system_time().micros() / 1000; // Convert to ms
+        Ok(self.bot(&bot_id).await?.follower_count(now).await?)
     }
 
-    /// Get the 24-hour accuracy metrics
-    async fn accuracy_24h(&self) -> &AccuracyMetrics {
-        &self.accuracy_24h
+    /// Get every account with an active subscription
+    async fn followers(&self, bot_id: String) -> async_graphql::Result<Vec<Follower>> {
+        let now = self.runtime.system_time().micros() / 1000; // Convert to ms
+        Ok(self.bot(&bot_id).await?.active_followers(now).await?)
     }
 
-    /// Get the number of followers
-    async fn follower_count(&self) -> u64 {
-        self.follower_count
+    /// Check whether an account has an active subscription
+    async fn is_following(
+        &self,
+        bot_id: String,
+        owner: AccountOwner,
+    ) -> async_graphql::Result<bool> {
+        let now = self.runtime.system_time().micros() / 1000; // Convert to ms
+        Ok(self.bot(&bot_id).await?.active_follower(&owner, now).await?.is_some())
+    }
+
+    /// Get the subscription tiers followers can pay for
+    async fn subscription_tiers(
+        &self,
+        bot_id: String,
+    ) -> async_graphql::Result<Vec<SubscriptionTier>> {
+        Ok(self.bot(&bot_id).await?.offered_tiers().await?)
+    }
+
+    /// Get the subscription revenue the operator can withdraw
+    async fn revenue(&self, bot_id: String) -> async_graphql::Result<Amount> {
+        Ok(*self.bot(&bot_id).await?.revenue.get())
     }
 }
 
+impl BotQueryRoot {
+    /// Load a hosted bot, failing if it does not exist
+    async fn bot(&self, bot_id: &str) -> async_graphql::Result<impl Deref<Target = Bot> + '_> {
+        self.state.bots.try_load_entry(bot_id).await?
+            .ok_or_else(|| format!("Unknown bot: {}", bot_id).into())
+    }
+
+    /// Load a window's metrics and expire resolutions that fell out of it since they were stored
+    async fn current_accuracy(
+        &self,
+        bot_id: &str,
+        asset: Option<&str>,
+        window: MetricsWindow,
+    ) -> async_graphql::Result<AccuracyMetrics> {
+        let bot = self.bot(bot_id).await?;
+        let mut metrics = match asset {
+            Some(asset) => bot.asset_window_accuracy(asset, window).await?,
+            None => bot.window_accuracy(window).await?,
+        };
+        metrics.advance_to(self.runtime.system_time().micros() / 1000); // Convert to ms
+        Ok(metrics)
+    }
+}
+
+/// GraphQL mutation root: `executePrediction` plus one mutation per `Operation`
+#[derive(MergedObject)]
+struct MutationRoot(
+    BotMutationRoot,
+    <Operation as GraphQLMutationRoot<BotStateService>>::MutationRoot,
+);
+
 /// GraphQL mutation root for bot predictions
 struct BotMutationRoot {
     runtime: Arc<ServiceRuntime<BotStateService>>,
@@ -331,20 +576,47 @@ struct BotMutationRoot {
 
 #[Object]
 impl BotMutationRoot {
-    /// Execute a prediction with the specified strategy
+    /// Execute a prediction for the bot `bot_id` with the specified strategy
+    ///
+    /// Strategies come from `StrategyRegistry`; currently supports:
+    /// - "gemma": Calls Gemma 3 27B (or the configured `model_id`) via inference.net
+    /// - "deepseek": Calls DeepSeek V3 via the DeepSeek API
+    /// - "qwen-vertex": Calls Qwen 3 Coder 480B via Vertex AI (us-south1)
+    /// - "gpt-oss-vertex": Calls GPT OSS 120B via Vertex AI (global)
+    /// - "technical": Votes with SMA, MACD and RSI indicators (no API key needed)
+    /// - "ensemble": Combines the configured `ensemble` sub-strategies by confidence-weighted
+    ///   vote and weighted-median price
     ///
-    /// Currently supports:
-    /// - "gemma": Calls Gemma 3 27B via inference.net
-    /// - "deepseek": Calls bot-service REST API (future)
-    /// - "qwen-vertex": Calls bot-service REST API (future)
-    /// - "gpt-oss-vertex": Calls bot-service REST API (future)
+    /// `symbol` is the Binance trading pair to predict, one of the configured `symbols`
+    /// (default: the first of them), so one bot can cover several assets.
     ///
-    /// Returns the generated signal or error message
-    /// API key is passed through the proxy transparently to inference.net
+    /// `horizon_ms` is how far ahead the prediction targets, as string (default 1 hour,
+    /// from 1 minute to 30 days); the signal must be resolved within the grace window
+    /// around that target.
+    ///
+    /// With a `salt`, the prediction is committed instead of submitted in cleartext, so it
+    /// is scored once the returned signal is revealed with `revealPrediction` and the same
+    /// salt before its target timestamp (the commitment id is read with `latestCommitment`).
+    /// The node service only returns the block hash of a mutation, so clients that reveal
+    /// run `prediction`, `commitmentHash` and `commitPrediction` instead.
+    ///
+    /// Without a `salt`, a `SubmitPrediction` is scheduled. Such a signal is published and
+    /// resolved but never scored, since only revealed commitments count towards accuracy.
+    ///
+    /// `api_key` is passed through the proxy to the provider unchanged (a GCP access token
+    /// for Vertex AI).
+    ///
+    /// Returns the generated signal, or an error message if the market data, strategy or
+    /// LLM answer failed. Nothing is scheduled on error.
+    #[allow(clippy::too_many_arguments)]
     async fn execute_prediction(
         &self,
+        bot_id: String,
         strategy: String,
+        symbol: Option<String>,
         api_key: Option<String>,
+        horizon_ms: Option<String>,
+        salt: Option<String>,
     ) -> Result<Signal, String> {
         // Create temporary service instance to access helper methods
         let state = BotState::load(self.runtime.root_view_storage_context())
@@ -352,32 +624,40 @@ impl BotMutationRoot {
             .map_err(|e| format!("Failed to load state: {}", e))?;
 
         let service = BotStateService {
-            state,
+            state: Arc::new(state),
             runtime: self.runtime.clone(),
         };
-
-        // Fetch market data
-        let market_data = service.fetch_market_data()?;
-
-        // Route to appropriate strategy
-        let signal = match strategy.as_str() {
-            "gemma" => {
-                let key = api_key.ok_or_else(|| "API key required for inference.net".to_string())?;
-                service.call_inference_net(&market_data, &key)?
-            }
-            "deepseek" | "qwen-vertex" | "gpt-oss-vertex" => {
-                // TODO: Call bot-service REST API
-                return Err(format!("Strategy '{}' not yet implemented in service layer. Use bot-service directly.", strategy));
-            }
-            _ => {
-                return Err(format!("Unknown strategy: {}", strategy));
-            }
-        };
+        let signal = service.predict(&strategy, symbol, api_key, horizon_ms)?;
+
+        if let Some(salt) = salt {
+            let prediction = CommittedPrediction {
+                bot_id: bot_id.clone(),
+                chain_id: self.runtime.chain_id(),
+                asset: signal.asset.clone(),
+                action: signal.action,
+                reference_price_micro: signal.reference_price_micro,
+                predicted_price_micro: signal.predicted_price_micro,
+                confidence_bps: signal.confidence_bps,
+                reasoning: signal.reasoning.clone(),
+                salt,
+            };
+            self.runtime.schedule_operation(&Operation::CommitPrediction {
+                bot_id,
+                asset: signal.asset.clone(),
+                commitment: prediction.hash(),
+                horizon_ms: (signal.target_timestamp - signal.timestamp).to_string(),
+            });
+            return Ok(signal);
+        }
 
         // Schedule operation to submit prediction
         self.runtime.schedule_operation(&Operation::SubmitPrediction {
+            bot_id,
+            asset: signal.asset.clone(),
             timestamp: signal.timestamp.to_string(),
+            horizon_ms: (signal.target_timestamp - signal.timestamp).to_string(),
             action: signal.action,
+            reference_price_micro: signal.reference_price_micro.to_string(),
             predicted_price_micro: signal.predicted_price_micro.to_string(),
             confidence_bps: signal.confidence_bps,
             reasoning: signal.reasoning.clone(),
@@ -392,66 +672,264 @@ mod tests {
     use std::sync::Arc;
 
     use async_graphql::{Request, Response, Value};
-    use futures::FutureExt as _;
-    use linera_sdk::{util::BlockingWait, views::View, Service, ServiceRuntime};
+    use linera_sdk::{
+        linera_base_types::{AccountOwner, CryptoHash, Timestamp},
+        util::BlockingWait,
+        views::View,
+        Service, ServiceRuntime,
+    };
     use serde_json::json;
 
-    use bot_state::{AccuracyMetrics, BotState};
+    use bot_state::{Action, Bot, BotState, Follower, Operation, Signal};
 
     use super::BotStateService;
 
     #[test]
-    fn test_query_bot_id() {
-        let bot_id = "test-bot".to_string();
+    fn test_query_bots() {
         let runtime = Arc::new(ServiceRuntime::<BotStateService>::new());
         let mut state = BotState::load(runtime.root_view_storage_context())
             .blocking_wait()
             .expect("Failed to read from mock key value store");
-        state.bot_id.set(bot_id.clone());
-        state.follower_count.set(0);
-        state.accuracy_24h.set(AccuracyMetrics::default());
+        create_bot(&mut state, "test-bot");
 
         let service = BotStateService {
-            state,
+            state: Arc::new(state),
             runtime: runtime.clone(),
         };
 
-        let request = Request::new("{ botId }");
+        let request = Request::new("{ bots }");
 
         let response = service
             .handle_query(request)
-            .now_or_never()
-            .expect("Query should not await anything");
+            .blocking_wait();
 
-        let expected = Response::new(Value::from_json(json!({"botId": "test-bot"})).unwrap());
+        let expected = Response::new(Value::from_json(json!({"bots": ["test-bot"]})).unwrap());
 
         assert_eq!(response, expected);
+
+        let request = Request::new(r#"{ operator(botId: "missing") }"#);
+        let response = service
+            .handle_query(request)
+            .blocking_wait();
+        assert_eq!(response.errors[0].message, "Unknown bot: missing");
     }
 
     #[test]
     fn test_query_follower_count() {
+        // Dave's subscription expired before now
+        let runtime = Arc::new(
+            ServiceRuntime::<BotStateService>::new().with_system_time(Timestamp::from(3000 * 1000)),
+        );
+        let mut state = BotState::load(runtime.root_view_storage_context())
+            .blocking_wait()
+            .expect("Failed to read from mock key value store");
+        let bot = create_bot(&mut state, "test");
+        let subscriptions = [("alice", 5000), ("bob", 5000), ("carol", 5000), ("dave", 2000)];
+        for (name, expires_at) in subscriptions {
+            let owner = AccountOwner::from(CryptoHash::test_hash(name));
+            bot
+                .insert_follower(Follower {
+                    owner,
+                    followed_at: 1000,
+                    tier: "monthly".to_string(),
+                    expires_at,
+                })
+                .blocking_wait()
+                .expect("Failed to store follower");
+        }
+
+        let service = BotStateService {
+            state: Arc::new(state),
+            runtime: runtime.clone(),
+        };
+
+        let request = Request::new(r#"{ followerCount(botId: "test") }"#);
+
+        let response = service
+            .handle_query(request)
+            .blocking_wait();
+
+        let expected = Response::new(Value::from_json(json!({"followerCount": 3})).unwrap());
+
+        assert_eq!(response, expected);
+    }
+
+    #[test]
+    fn test_query_signal_history_pagination() {
         let runtime = Arc::new(ServiceRuntime::<BotStateService>::new());
         let mut state = BotState::load(runtime.root_view_storage_context())
             .blocking_wait()
             .expect("Failed to read from mock key value store");
-        state.bot_id.set("test".to_string());
-        state.follower_count.set(42);
-        state.accuracy_24h.set(AccuracyMetrics::default());
+        let bot = create_bot(&mut state, "test");
+        for timestamp in [1000, 2000, 3000] {
+            bot
+                .push_signal(signal(timestamp))
+                .blocking_wait()
+                .expect("Failed to store signal");
+        }
 
         let service = BotStateService {
-            state,
+            state: Arc::new(state),
             runtime: runtime.clone(),
         };
 
-        let request = Request::new("{ followerCount }");
+        let request = Request::new(
+            r#"{ signals(botId: "test", limit: 2) { signals { id timestamp } nextCursor } }"#,
+        );
+        let response = service
+            .handle_query(request)
+            .blocking_wait();
+        let expected = Response::new(
+            Value::from_json(json!({
+                "signals": {
+                    "signals": [{"id": 2, "timestamp": 3000}, {"id": 1, "timestamp": 2000}],
+                    "nextCursor": "1"
+                }
+            }))
+            .unwrap(),
+        );
+        assert_eq!(response, expected);
 
+        let request = Request::new(
+            r#"{ signals(botId: "test", cursor: "1", limit: 2) {
+                signals { id timestamp } nextCursor
+            } }"#,
+        );
         let response = service
             .handle_query(request)
-            .now_or_never()
-            .expect("Query should not await anything");
+            .blocking_wait();
+        let expected = Response::new(
+            Value::from_json(json!({
+                "signals": {
+                    "signals": [{"id": 0, "timestamp": 1000}],
+                    "nextCursor": null
+                }
+            }))
+            .unwrap(),
+        );
+        assert_eq!(response, expected);
+    }
+
+    #[test]
+    fn test_query_signals_in_time_range() {
+        let runtime = Arc::new(ServiceRuntime::<BotStateService>::new());
+        let mut state = BotState::load(runtime.root_view_storage_context())
+            .blocking_wait()
+            .expect("Failed to read from mock key value store");
+        let bot = create_bot(&mut state, "test");
+        // A revealed signal keeps its commitment time, so the newest id can be the oldest
+        for timestamp in [2000, 3000, 500] {
+            bot
+                .push_signal(signal(timestamp))
+                .blocking_wait()
+                .expect("Failed to store signal");
+        }
 
-        let expected = Response::new(Value::from_json(json!({"followerCount": 42})).unwrap());
+        let service = BotStateService {
+            state: Arc::new(state),
+            runtime: runtime.clone(),
+        };
 
+        let request = Request::new(
+            r#"{ signals(botId: "test", fromTimestamp: "1000", toTimestamp: "2500") {
+                signals { id timestamp } nextCursor
+            } }"#,
+        );
+        let response = service
+            .handle_query(request)
+            .blocking_wait();
+        let expected = Response::new(
+            Value::from_json(json!({
+                "signals": {
+                    "signals": [{"id": 0, "timestamp": 2000}],
+                    "nextCursor": null
+                }
+            }))
+            .unwrap(),
+        );
         assert_eq!(response, expected);
     }
+
+    #[test]
+    fn test_reveal_prediction_mutation() {
+        let runtime = Arc::new(ServiceRuntime::<BotStateService>::new());
+        let state = BotState::load(runtime.root_view_storage_context())
+            .blocking_wait()
+            .expect("Failed to read from mock key value store");
+
+        let service = BotStateService {
+            state: Arc::new(state),
+            runtime: runtime.clone(),
+        };
+
+        let request = Request::new(
+            r#"mutation { revealPrediction(
+                botId: "test", commitmentId: "3", action: BUY, referencePriceMicro: "2450000000",
+                predictedPriceMicro: "2500000000", confidenceBps: 7500, salt: "salt",
+                reasoning: "Test"
+            ) }"#,
+        );
+        let response = service
+            .handle_query(request)
+            .blocking_wait();
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+        let operations = runtime.scheduled_operations::<Operation>();
+        assert!(matches!(
+            operations.as_slice(),
+            [Operation::RevealPrediction { bot_id, commitment_id, action: Action::Buy, salt, .. }]
+                if bot_id == "test" && commitment_id == "3" && salt == "salt"
+        ));
+    }
+
+    #[test]
+    fn test_prediction_rejects_invalid_horizon() {
+        let runtime = Arc::new(ServiceRuntime::<BotStateService>::new());
+        let state = BotState::load(runtime.root_view_storage_context())
+            .blocking_wait()
+            .expect("Failed to read from mock key value store");
+
+        let service = BotStateService {
+            state: Arc::new(state),
+            runtime: runtime.clone(),
+        };
+
+        for horizon in ["0", "59999", "2592000001"] {
+            let request = Request::new(format!(
+                r#"{{ prediction(strategy: "technical", horizonMs: "{}") {{ timestamp }} }}"#,
+                horizon
+            ));
+            let response = service
+                .handle_query(request)
+                .blocking_wait();
+            assert_eq!(
+                response.errors[0].message,
+                format!("Invalid horizon: {} ms is not between 60000 and 2592000000 ms", horizon)
+            );
+        }
+    }
+
+    fn signal(timestamp: u64) -> Signal {
+        Signal {
+            id: 0,
+            timestamp,
+            target_timestamp: timestamp + 3_600_000,
+            asset: "ETHUSDT".to_string(),
+            action: Action::Buy,
+            reference_price_micro: 2_450_000_000,
+            predicted_price_micro: 2_500_000_000,
+            confidence_bps: 7500,
+            reasoning: "Test".to_string(),
+            actual_price_micro: None,
+            commitment: None,
+        }
+    }
+
+    fn create_bot<'a>(state: &'a mut BotState, bot_id: &str) -> &'a mut Bot {
+        let bot = state.bots.load_entry_mut(bot_id)
+            .blocking_wait()
+            .expect("Failed to create bot");
+        bot.bot_id.set(bot_id.to_string());
+        bot
+    }
 }
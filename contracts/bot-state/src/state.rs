@@ -1,34 +1,142 @@
-use linera_sdk::views::{linera_views, RegisterView, RootView, ViewStorageContext};
+use bot_registry::{MetricsReport, WindowMetrics};
+use linera_sdk::linera_base_types::{
+    Account, AccountOwner, Amount, ApplicationId, BcsHashable, ChainId, CryptoHash,
+};
+use linera_sdk::views::{
+    linera_views, CollectionView, CustomMapView, CustomSerialize, LogView, MapView,
+    RegisterView, RootView, SetView, View, ViewError, ViewStorageContext,
+};
 use serde::{Deserialize, Serialize};
 
-/// The application state for a trading bot
+use crate::error::BotStateError;
+
+/// The application state: every bot hosted by this deployment
 #[derive(RootView, async_graphql::SimpleObject)]
 #[view(context = ViewStorageContext)]
 pub struct BotState {
+    /// Account allowed to create bots (defaults to the creator)
+    pub admin: RegisterView<Option<AccountOwner>>,
+
+    /// Bot registry application the bots report their metrics to, if any
+    pub registry: RegisterView<Option<ApplicationId>>,
+
+    /// Bots hosted by this application, keyed by bot id (kept on the bot chain)
+    pub bots: CollectionView<String, Bot>,
+
+    /// Signals received from the bot chain, keyed by bot id and then signal id
+    /// (kept on follower chains)
+    pub received_signals: CollectionView<String, MapView<u64, Signal>>,
+}
+
+/// The state of a single trading bot
+#[derive(View, async_graphql::SimpleObject)]
+#[view(context = ViewStorageContext)]
+pub struct Bot {
     /// Unique identifier for this bot (e.g., "momentum", "sentiment")
     pub bot_id: RegisterView<String>,
 
-    /// The latest signal published by this bot
-    pub latest_signal: RegisterView<Option<Signal>>,
+    /// Retired bots keep their history but accept no new predictions or followers
+    pub retired: RegisterView<bool>,
+
+    /// Account allowed to submit predictions and manage roles
+    pub operator: RegisterView<Option<AccountOwner>>,
+
+    /// Account allowed to resolve predictions with actual prices
+    pub resolver: RegisterView<Option<AccountOwner>>,
+
+    /// Every signal published by this bot, keyed by sequential id
+    pub signals: MapView<u64, Signal>,
+
+    /// Id that will be assigned to the next submitted signal
+    pub next_signal_id: RegisterView<u64>,
+
+    /// Signal ids keyed by asset and then timestamp, used to look signals up on resolution
+    pub signal_ids_by_timestamp: CollectionView<String, MapView<u64, u64>>,
+
+    /// Id of the latest signal for each asset
+    pub latest_signal_ids: MapView<String, u64>,
+
+    /// Ids of signals that have not been resolved yet
+    pub pending_signals: SetView<u64>,
+
+    /// Rolling accuracy metrics over every asset for each time window, updated together
+    /// on resolution
+    pub accuracy: MapView<MetricsWindow, AccuracyMetrics>,
+
+    /// Rolling accuracy metrics keyed by asset and then time window
+    pub asset_accuracy: CollectionView<String, MapView<MetricsWindow, AccuracyMetrics>>,
+
+    /// Accounts following this bot and their paid subscriptions
+    pub followers: MapView<AccountOwner, Follower>,
 
-    /// Rolling accuracy metrics for the last 24 hours
-    pub accuracy_24h: RegisterView<AccuracyMetrics>,
+    /// Owners in `followers` keyed by subscription expiry, earliest first, so expired
+    /// subscriptions are found without scanning every follower
+    #[graphql(skip)]
+    pub follower_expiries: CustomMapView<ExpiryKey, Vec<AccountOwner>>,
 
-    /// Total count of users following this bot
-    pub follower_count: RegisterView<u64>,
+    /// Number of entries in `followers`, expired or not
+    pub follower_entries: RegisterView<u64>,
+
+    /// Subscription tiers offered to followers, keyed by name
+    pub subscription_tiers: MapView<String, SubscriptionTier>,
+
+    /// Subscription payments held by the application that the operator can withdraw
+    pub revenue: RegisterView<Amount>,
+
+    /// Chains that receive every new and resolved signal
+    pub subscribers: SetView<ChainId>,
+
+    /// Native tokens staked by the operator and held by the application
+    pub stake: RegisterView<Amount>,
+
+    /// Rules under which the stake is slashed (no slashing if unset)
+    pub slashing_policy: RegisterView<Option<SlashingPolicy>>,
+
+    /// Every slash applied to the stake, oldest first
+    pub slashes: LogView<Slash>,
+
+    /// Latest signal deadline that has already been slashed as missed (milliseconds)
+    pub missed_deadline_slashed: RegisterView<u64>,
+
+    /// Chain time at which the latest signal or commitment was accepted (milliseconds)
+    pub last_signal_at: RegisterView<u64>,
+
+    /// Predictions committed as hashes, keyed by sequential id
+    pub commitments: MapView<u64, Commitment>,
+
+    /// Id that will be assigned to the next commitment
+    pub next_commitment_id: RegisterView<u64>,
+
+    /// Id of the latest commitment for each asset
+    pub latest_commitment_ids: MapView<String, u64>,
+
+    /// Ids of commitments that were neither revealed nor scored as missed yet
+    pub open_commitments: SetView<u64>,
 }
 
 /// A prediction signal generated by the bot
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, async_graphql::SimpleObject)]
 #[graphql(input_name = "SignalInput")]
 pub struct Signal {
+    /// Sequential id assigned by the contract when the signal is stored
+    pub id: u64,
+
     /// Unix timestamp (milliseconds) when signal was generated
     pub timestamp: u64,
 
+    /// Unix timestamp (milliseconds) at which the signal should be resolved
+    pub target_timestamp: u64,
+
+    /// Trading pair the signal is about (e.g., "ETHUSDT", "BTCUSDT")
+    pub asset: String,
+
     /// Trading action recommendation
     pub action: Action,
 
-    /// Predicted ETH price in micro-USD (multiply USD by 1_000_000)
+    /// Market price in micro-USD when the signal was issued; the action is scored against it
+    pub reference_price_micro: u64,
+
+    /// Predicted price of the asset in micro-USD (multiply USD by 1_000_000)
     /// Example: $3500.25 = 3_500_250_000
     pub predicted_price_micro: u64,
 
@@ -41,6 +149,143 @@ pub struct Signal {
 
     /// Actual price at resolution time in micro-USD (populated later)
     pub actual_price_micro: Option<u64>,
+
+    /// Commitment the signal was revealed from; only committed signals are scored
+    #[serde(default)]
+    pub commitment: Option<CryptoHash>,
+}
+
+/// A prediction committed as a hash before its content is revealed
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, async_graphql::SimpleObject)]
+pub struct Commitment {
+    /// Sequential id assigned by the contract
+    pub id: u64,
+
+    /// Trading pair the hidden prediction is about
+    pub asset: String,
+
+    /// Hash of the `CommittedPrediction` that will be revealed
+    pub hash: CryptoHash,
+
+    /// Chain time (milliseconds) of the commitment, used as the signal timestamp
+    pub committed_at: u64,
+
+    /// Unix timestamp (milliseconds) at which the prediction should be resolved;
+    /// the prediction must be revealed before it
+    pub target_timestamp: u64,
+
+    /// Id of the signal created by the reveal, if revealed
+    pub signal_id: Option<u64>,
+}
+
+/// The hidden content of a commitment
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CommittedPrediction {
+    /// Bot and chain the commitment is made on, so a reveal cannot be replayed elsewhere
+    pub bot_id: String,
+    pub chain_id: ChainId,
+    pub asset: String,
+    pub action: Action,
+    pub reference_price_micro: u64,
+    pub predicted_price_micro: u64,
+    pub confidence_bps: u64,
+    pub reasoning: String,
+    /// Random value that keeps the commitment from being guessed
+    pub salt: String,
+}
+
+impl BcsHashable<'_> for CommittedPrediction {}
+
+/// Maximum length of an asset symbol
+pub const MAX_ASSET_LEN: usize = 20;
+
+/// Default for how far from its target timestamp a signal may be resolved (10 minutes)
+pub const RESOLUTION_GRACE_MS: u64 = 10 * 60 * 1000;
+
+/// How far ahead of the chain's time a submitted signal timestamp may be (5 minutes)
+pub const MAX_TIMESTAMP_DRIFT_MS: u64 = 5 * 60 * 1000;
+
+/// Default band around the reference price within which a Hold signal is correct (2%)
+pub const DEFAULT_HOLD_BAND_BPS: u64 = 200;
+
+/// An account following the bot
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, async_graphql::SimpleObject)]
+pub struct Follower {
+    pub owner: AccountOwner,
+
+    /// Unix timestamp (milliseconds) when the account started following
+    pub followed_at: u64,
+
+    /// Name of the subscription tier paid for most recently
+    pub tier: String,
+
+    /// Unix timestamp (milliseconds) when the paid subscription ends
+    pub expires_at: u64,
+}
+
+/// A subscription tier followers can pay for
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, async_graphql::SimpleObject)]
+pub struct SubscriptionTier {
+    pub name: String,
+
+    /// Price of one period in native tokens (zero for a free tier)
+    pub price: Amount,
+
+    /// Length of one period in milliseconds
+    pub period_ms: u64,
+}
+
+/// Rules for slashing the operator's stake
+#[derive(
+    Debug, Clone, Serialize, Deserialize, PartialEq, Eq, async_graphql::SimpleObject,
+    async_graphql::InputObject,
+)]
+#[graphql(input_name = "SlashingPolicyInput")]
+pub struct SlashingPolicy {
+    /// Account receiving slashed tokens
+    pub beneficiary: Account,
+
+    /// Share of the current stake slashed per violation, in basis points
+    pub slash_bps: u64,
+
+    /// Window whose directional accuracy is checked after every resolution
+    pub accuracy_window: MetricsWindow,
+
+    /// Directional accuracy (basis points) below which the stake is slashed
+    pub min_accuracy_bps: u64,
+
+    /// Resolved predictions required in the window before accuracy is enforced
+    pub min_predictions: u64,
+
+    /// Maximum time between two signals in milliseconds (no schedule if unset)
+    pub max_signal_interval_ms: Option<u64>,
+}
+
+/// Why the stake was slashed
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, async_graphql::Enum)]
+pub enum SlashReason {
+    /// Rolling directional accuracy fell below the policy threshold
+    LowAccuracy,
+    /// No signal was submitted before the scheduled deadline
+    MissedSignal,
+}
+
+/// A slash applied to the operator's stake
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, async_graphql::SimpleObject)]
+pub struct Slash {
+    /// Unix timestamp (milliseconds) when the slash was applied
+    pub timestamp: u64,
+
+    pub reason: SlashReason,
+
+    /// Tokens sent to the beneficiary
+    pub amount: Amount,
+
+    /// Directional accuracy in basis points at the time (`LowAccuracy` only)
+    pub accuracy_bps: Option<u64>,
+
+    /// Deadline that was missed, in milliseconds (`MissedSignal` only)
+    pub missed_deadline: Option<u64>,
 }
 
 /// Trading action types
@@ -51,12 +296,64 @@ pub enum Action {
     Hold,
 }
 
-/// Accuracy metrics computed over a time window
+/// Time windows over which accuracy metrics are aggregated
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, async_graphql::Enum)]
+pub enum MetricsWindow {
+    OneHour,
+    OneDay,
+    SevenDays,
+    ThirtyDays,
+    AllTime,
+}
+
+impl MetricsWindow {
+    /// Every window, in the order they are reported
+    pub const ALL: [MetricsWindow; 5] = [
+        MetricsWindow::OneHour,
+        MetricsWindow::OneDay,
+        MetricsWindow::SevenDays,
+        MetricsWindow::ThirtyDays,
+        MetricsWindow::AllTime,
+    ];
+
+    /// Duration of one bucket of the window's ring buffer in milliseconds
+    pub fn bucket_duration_ms(&self) -> u64 {
+        match self {
+            MetricsWindow::OneHour => 60_000, // 1 minute
+            MetricsWindow::OneDay => 3_600_000, // 1 hour
+            MetricsWindow::SevenDays => 6 * 3_600_000, // 6 hours
+            MetricsWindow::ThirtyDays => 24 * 3_600_000, // 1 day
+            MetricsWindow::AllTime => u64::MAX,
+        }
+    }
+
+    /// Number of buckets in the window's ring buffer (0 = never expires)
+    pub fn bucket_count(&self) -> u64 {
+        match self {
+            MetricsWindow::OneHour => 60,
+            MetricsWindow::OneDay => 24,
+            MetricsWindow::SevenDays => 28,
+            MetricsWindow::ThirtyDays => 30,
+            MetricsWindow::AllTime => 0,
+        }
+    }
+}
+
+/// Accuracy metrics computed over a rolling time window
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, async_graphql::SimpleObject)]
 pub struct AccuracyMetrics {
+    /// Time window these metrics cover
+    pub window: MetricsWindow,
+
     /// Root Mean Square Error in micro-USD
     pub rmse_micro: u64,
 
+    /// Mean Absolute Error in micro-USD
+    pub mae_micro: u64,
+
+    /// Mean signed error (predicted - actual) in micro-USD; positive means the bot overshoots
+    pub bias_micro: i64,
+
     /// Directional accuracy in basis points (0-10000, where 10000 = 100%)
     /// Example: 95.5% = 9550
     pub directional_accuracy_bps: u64,
@@ -67,59 +364,603 @@ pub struct AccuracyMetrics {
     /// Number of correct directional calls
     pub correct_predictions: u64,
 
+    /// Number of commitments not revealed before their target timestamp, counted in
+    /// `total_predictions` as wrong calls but left out of the error statistics
+    pub missed_predictions: u64,
+
     /// Last updated timestamp
     pub last_updated: u64,
+
+    /// Sum of squared errors over the window, in micro-USD squared
+    #[graphql(skip)]
+    pub squared_error_sum: u128,
+
+    /// Sum of absolute errors over the window, in micro-USD
+    #[graphql(skip)]
+    pub absolute_error_sum: u128,
+
+    /// Sum of signed errors (predicted - actual) over the window, in micro-USD
+    #[graphql(skip)]
+    pub signed_error_sum: i128,
+
+    /// Ring buffer of per-bucket totals, indexed by `bucket index % bucket count`
+    #[graphql(skip)]
+    pub buckets: Vec<MetricsBucket>,
+
+    /// Bucket index (timestamp / bucket duration) of the newest bucket
+    #[graphql(skip)]
+    pub current_bucket: u64,
+}
+
+/// Totals for the resolutions that fell into one bucket of the rolling window
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct MetricsBucket {
+    pub total_predictions: u64,
+    pub correct_predictions: u64,
+    pub missed_predictions: u64,
+    pub squared_error_sum: u128,
+    pub absolute_error_sum: u128,
+    pub signed_error_sum: i128,
 }
 
 impl Default for AccuracyMetrics {
     fn default() -> Self {
+        Self::new(MetricsWindow::OneDay)
+    }
+}
+
+impl AccuracyMetrics {
+    /// Create empty metrics for the given window
+    pub fn new(window: MetricsWindow) -> Self {
         Self {
+            window,
             rmse_micro: 0,
+            mae_micro: 0,
+            bias_micro: 0,
             directional_accuracy_bps: 0,
             total_predictions: 0,
             correct_predictions: 0,
+            missed_predictions: 0,
             last_updated: 0,
+            squared_error_sum: 0,
+            absolute_error_sum: 0,
+            signed_error_sum: 0,
+            buckets: vec![MetricsBucket::default(); window.bucket_count() as usize],
+            current_bucket: 0,
         }
     }
 }
 
+impl BotState {
+    /// Get the ids of every bot hosted by this application
+    pub async fn bot_ids(&self) -> Result<Vec<String>, ViewError> {
+        self.bots.indices().await
+    }
+}
+
+impl Bot {
+    /// Check that a bot id is non-empty and at most 64 characters
+    pub fn validate_id(bot_id: &str) -> Result<(), BotStateError> {
+        if bot_id.is_empty() || bot_id.len() > 64 {
+            return Err(BotStateError::InvalidBotId);
+        }
+        Ok(())
+    }
+
+    /// Get the most recently submitted signal, if any
+    pub async fn latest_signal(&self) -> Result<Option<Signal>, ViewError> {
+        match self.next_signal_id.get().checked_sub(1) {
+            Some(id) => self.signals.get(&id).await,
+            None => Ok(None),
+        }
+    }
+
+    /// Get the most recently submitted signal for `asset`, if any
+    pub async fn latest_signal_for(&self, asset: &str) -> Result<Option<Signal>, ViewError> {
+        match self.latest_signal_ids.get(asset).await? {
+            Some(id) => self.signals.get(&id).await,
+            None => Ok(None),
+        }
+    }
+
+    /// Get the assets this bot has published signals for
+    pub async fn assets(&self) -> Result<Vec<String>, ViewError> {
+        self.latest_signal_ids.indices().await
+    }
+
+    /// Get the id of the signal for `asset` issued at `timestamp`, if any
+    pub async fn signal_id_at(
+        &self,
+        asset: &str,
+        timestamp: u64,
+    ) -> Result<Option<u64>, ViewError> {
+        match self.signal_ids_by_timestamp.try_load_entry(asset).await? {
+            Some(ids) => ids.get(&timestamp).await,
+            None => Ok(None),
+        }
+    }
+
+    /// Append a signal to the history, assigning it the next sequential id
+    /// and marking it as pending resolution
+    pub async fn push_signal(&mut self, mut signal: Signal) -> Result<u64, ViewError> {
+        let id = *self.next_signal_id.get();
+        signal.id = id;
+        self.signal_ids_by_timestamp
+            .load_entry_mut(&signal.asset)
+            .await?
+            .insert(&signal.timestamp, id)?;
+        self.latest_signal_ids.insert(&signal.asset, id)?;
+        self.pending_signals.insert(&id)?;
+        self.signals.insert(&id, signal)?;
+        self.next_signal_id.set(id + 1);
+        Ok(id)
+    }
+
+    /// Get the accuracy metrics over every asset for a window as stored at the last
+    /// resolution
+    pub async fn window_accuracy(&self, window: MetricsWindow) -> Result<AccuracyMetrics, ViewError> {
+        Ok(self
+            .accuracy
+            .get(&window)
+            .await?
+            .unwrap_or_else(|| AccuracyMetrics::new(window)))
+    }
+
+    /// Get the accuracy metrics of one asset for a window as stored at the last resolution
+    pub async fn asset_window_accuracy(
+        &self,
+        asset: &str,
+        window: MetricsWindow,
+    ) -> Result<AccuracyMetrics, ViewError> {
+        let metrics = match self.asset_accuracy.try_load_entry(asset).await? {
+            Some(accuracy) => accuracy.get(&window).await?,
+            None => None,
+        };
+        Ok(metrics.unwrap_or_else(|| AccuracyMetrics::new(window)))
+    }
+
+    /// Record a resolved signal in every metrics window, both over every asset and for the
+    /// signal's asset, returning the updated metrics over every asset
+    pub async fn record_resolution(
+        &mut self,
+        signal: &Signal,
+        current_timestamp: u64,
+        hold_band_bps: u64,
+    ) -> Result<Vec<AccuracyMetrics>, ViewError> {
+        self.update_accuracy(&signal.asset, |metrics| {
+            metrics.update(signal, current_timestamp, hold_band_bps)
+        })
+        .await
+    }
+
+    /// Record a commitment that was not revealed in time as a wrong call in every metrics
+    /// window, returning the updated metrics over every asset
+    pub async fn record_missed_reveal(
+        &mut self,
+        commitment: &Commitment,
+        current_timestamp: u64,
+    ) -> Result<Vec<AccuracyMetrics>, ViewError> {
+        self.update_accuracy(&commitment.asset, |metrics| metrics.record_miss(current_timestamp))
+            .await
+    }
+
+    /// Apply `update` to every metrics window over every asset and for `asset`, returning
+    /// the updated metrics over every asset
+    async fn update_accuracy(
+        &mut self,
+        asset: &str,
+        update: impl Fn(&mut AccuracyMetrics),
+    ) -> Result<Vec<AccuracyMetrics>, ViewError> {
+        let mut updated = Vec::new();
+        for window in MetricsWindow::ALL {
+            let mut metrics = self.window_accuracy(window).await?;
+            update(&mut metrics);
+            self.accuracy.insert(&window, metrics.clone())?;
+            updated.push(metrics);
+
+            let mut asset_metrics = self.asset_window_accuracy(asset, window).await?;
+            update(&mut asset_metrics);
+            self.asset_accuracy
+                .load_entry_mut(asset)
+                .await?
+                .insert(&window, asset_metrics)?;
+        }
+        Ok(updated)
+    }
+
+    /// Build the metrics report sent to the bot registry, with windows advanced to `now`
+    pub async fn registry_report(&self, now: u64) -> Result<MetricsReport, ViewError> {
+        let window_metrics = |mut metrics: AccuracyMetrics| {
+            metrics.advance_to(now);
+            WindowMetrics {
+                directional_accuracy_bps: metrics.directional_accuracy_bps,
+                rmse_micro: metrics.rmse_micro,
+                total_predictions: metrics.total_predictions,
+            }
+        };
+        Ok(MetricsReport {
+            one_day: window_metrics(self.window_accuracy(MetricsWindow::OneDay).await?),
+            all_time: window_metrics(self.window_accuracy(MetricsWindow::AllTime).await?),
+            follower_count: self.follower_count(now).await?,
+        })
+    }
+
+    /// Get the most recent commitment, if any
+    pub async fn latest_commitment(&self) -> Result<Option<Commitment>, ViewError> {
+        match self.next_commitment_id.get().checked_sub(1) {
+            Some(id) => self.commitments.get(&id).await,
+            None => Ok(None),
+        }
+    }
+
+    /// Get the most recent commitment for `asset`, if any
+    pub async fn latest_commitment_for(
+        &self,
+        asset: &str,
+    ) -> Result<Option<Commitment>, ViewError> {
+        match self.latest_commitment_ids.get(asset).await? {
+            Some(id) => self.commitments.get(&id).await,
+            None => Ok(None),
+        }
+    }
+
+    /// Get commitments whose reveal deadline passed at `now` without a reveal, oldest first
+    pub async fn unrevealed_commitments(&self, now: u64) -> Result<Vec<Commitment>, ViewError> {
+        let mut commitments = Vec::new();
+        self.commitments
+            .for_each_index_value(|_, commitment| {
+                if commitment.signal_id.is_none() && now > commitment.target_timestamp {
+                    commitments.push(commitment.into_owned());
+                }
+                Ok(())
+            })
+            .await?;
+        // Keys are not stored in numeric order
+        commitments.sort_unstable_by_key(|commitment| commitment.id);
+        Ok(commitments)
+    }
+
+    /// Close the open commitments whose reveal deadline passed at `now` and return them,
+    /// oldest first, so each is scored as missed only once
+    pub async fn take_missed_commitments(&mut self, now: u64) -> Result<Vec<Commitment>, ViewError> {
+        // Keys are not stored in numeric order
+        let mut ids = self.open_commitments.indices().await?;
+        ids.sort_unstable();
+        let mut missed = Vec::new();
+        for id in ids {
+            let Some(commitment) = self.commitments.get(&id).await? else {
+                continue;
+            };
+            if now > commitment.target_timestamp {
+                self.open_commitments.remove(&id)?;
+                missed.push(commitment);
+            }
+        }
+        Ok(missed)
+    }
+
+    /// Get the number of accounts whose subscription is active at `now` (milliseconds)
+    ///
+    /// Only reads the subscriptions that expired and were not pruned yet.
+    pub async fn follower_count(&self, now: u64) -> Result<u64, ViewError> {
+        let mut expired = 0;
+        self.follower_expiries
+            .for_each_index_value_while(|expires_at, owners| {
+                if expires_at.0 > now {
+                    return Ok(false);
+                }
+                expired += owners.len() as u64;
+                Ok(true)
+            })
+            .await?;
+        Ok(self.follower_entries.get().saturating_sub(expired))
+    }
+
+    /// Get all followers whose subscription is active at `now` (milliseconds)
+    pub async fn active_followers(&self, now: u64) -> Result<Vec<Follower>, ViewError> {
+        let mut followers = Vec::new();
+        self.followers
+            .for_each_index_value(|_, follower| {
+                if follower.is_active_at(now) {
+                    followers.push(follower.into_owned());
+                }
+                Ok(())
+            })
+            .await?;
+        Ok(followers)
+    }
+
+    /// Get the follower entry for `owner` if its subscription is active at `now`
+    pub async fn active_follower(
+        &self,
+        owner: &AccountOwner,
+        now: u64,
+    ) -> Result<Option<Follower>, ViewError> {
+        Ok(self
+            .followers
+            .get(owner)
+            .await?
+            .filter(|follower| follower.is_active_at(now)))
+    }
+
+    /// Store or replace the subscription of `follower.owner`
+    pub async fn insert_follower(&mut self, follower: Follower) -> Result<(), ViewError> {
+        let owner = follower.owner;
+        if !self.unindex_follower(&owner).await? {
+            let entries = *self.follower_entries.get();
+            self.follower_entries.set(entries + 1);
+        }
+        let expires_at = ExpiryKey(follower.expires_at);
+        let mut owners = self.follower_expiries.get(&expires_at).await?.unwrap_or_default();
+        owners.push(owner);
+        self.follower_expiries.insert(&expires_at, owners)?;
+        self.followers.insert(&owner, follower)
+    }
+
+    /// Remove the subscription of `owner`, returning whether there was one
+    pub async fn remove_follower(&mut self, owner: &AccountOwner) -> Result<bool, ViewError> {
+        if !self.unindex_follower(owner).await? {
+            return Ok(false);
+        }
+        self.followers.remove(owner)?;
+        let entries = *self.follower_entries.get();
+        self.follower_entries.set(entries.saturating_sub(1));
+        Ok(true)
+    }
+
+    /// Remove followers whose subscription expired at `now` (milliseconds)
+    ///
+    /// Only reads the expired subscriptions.
+    pub async fn prune_expired_followers(&mut self, now: u64) -> Result<(), ViewError> {
+        let mut expired = Vec::new();
+        self.follower_expiries
+            .for_each_index_value_while(|expires_at, owners| {
+                if expires_at.0 > now {
+                    return Ok(false);
+                }
+                expired.push((expires_at, owners.into_owned()));
+                Ok(true)
+            })
+            .await?;
+        let mut pruned = 0;
+        for (expires_at, owners) in expired {
+            self.follower_expiries.remove(&expires_at)?;
+            for owner in owners {
+                self.followers.remove(&owner)?;
+                pruned += 1;
+            }
+        }
+        let entries = *self.follower_entries.get();
+        self.follower_entries.set(entries.saturating_sub(pruned));
+        Ok(())
+    }
+
+    /// Drop `owner` from the expiry index, returning whether it had a subscription
+    async fn unindex_follower(&mut self, owner: &AccountOwner) -> Result<bool, ViewError> {
+        let Some(follower) = self.followers.get(owner).await? else {
+            return Ok(false);
+        };
+        let expires_at = ExpiryKey(follower.expires_at);
+        let mut owners = self.follower_expiries.get(&expires_at).await?.unwrap_or_default();
+        owners.retain(|indexed| indexed != owner);
+        if owners.is_empty() {
+            self.follower_expiries.remove(&expires_at)?;
+        } else {
+            self.follower_expiries.insert(&expires_at, owners)?;
+        }
+        Ok(true)
+    }
+
+    /// Get every subscription tier offered by this bot
+    pub async fn offered_tiers(&self) -> Result<Vec<SubscriptionTier>, ViewError> {
+        let mut tiers = Vec::new();
+        self.subscription_tiers
+            .for_each_index_value(|_, tier| {
+                tiers.push(tier.into_owned());
+                Ok(())
+            })
+            .await?;
+        Ok(tiers)
+    }
+
+    /// Get all signals that are still waiting to be resolved, oldest first
+    pub async fn unresolved_signals(&self) -> Result<Vec<Signal>, ViewError> {
+        // Keys are not stored in numeric order
+        let mut ids = self.pending_signals.indices().await?;
+        ids.sort_unstable();
+        let mut signals = Vec::new();
+        for id in ids {
+            if let Some(signal) = self.signals.get(&id).await? {
+                signals.push(signal);
+            }
+        }
+        Ok(signals)
+    }
+
+    /// Count the pending signals that may still be resolved after `now` (milliseconds) and
+    /// the open commitments that may still be revealed
+    pub async fn outstanding_predictions(&self, now: u64, grace_ms: u64) -> Result<u64, ViewError> {
+        let mut outstanding = 0;
+        for signal in self.unresolved_signals().await? {
+            if now <= signal.target_timestamp.saturating_add(grace_ms) {
+                outstanding += 1;
+            }
+        }
+        for id in self.open_commitments.indices().await? {
+            let commitment = self.commitments.get(&id).await?;
+            if commitment.is_some_and(|commitment| now <= commitment.target_timestamp) {
+                outstanding += 1;
+            }
+        }
+        Ok(outstanding)
+    }
+
+    /// Get the pending signals whose target timestamp has passed at `now` (milliseconds)
+    pub async fn overdue_signals(&self, now: u64) -> Result<Vec<Signal>, ViewError> {
+        let mut signals = self.unresolved_signals().await?;
+        signals.retain(|signal| signal.is_overdue_at(now));
+        Ok(signals)
+    }
+}
+
+impl CommittedPrediction {
+    /// Hash to commit to before revealing the prediction
+    pub fn hash(&self) -> CryptoHash {
+        CryptoHash::new(self)
+    }
+}
+
+/// Subscription expiry (milliseconds) as a map key that sorts in time order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpiryKey(pub u64);
+
+impl CustomSerialize for ExpiryKey {
+    fn to_custom_bytes(&self) -> Result<Vec<u8>, ViewError> {
+        Ok(self.0.to_be_bytes().to_vec())
+    }
+
+    fn from_custom_bytes(bytes: &[u8]) -> Result<Self, ViewError> {
+        let bytes = bytes.try_into().map_err(|_| ViewError::InconsistentEntries)?;
+        Ok(ExpiryKey(u64::from_be_bytes(bytes)))
+    }
+}
+
+impl Follower {
+    /// Check if the subscription is still running at `now` (milliseconds)
+    pub fn is_active_at(&self, now: u64) -> bool {
+        now < self.expires_at
+    }
+}
+
+impl SubscriptionTier {
+    /// Validate tier constraints
+    pub fn validate(&self) -> Result<(), BotStateError> {
+        if self.name.is_empty() || self.name.len() > 32 {
+            return Err(BotStateError::InvalidTierName);
+        }
+        if self.period_ms == 0 {
+            return Err(BotStateError::ZeroTierPeriod);
+        }
+        Ok(())
+    }
+
+    /// Price of `periods` periods of this tier
+    pub fn price_for(&self, periods: u64) -> Amount {
+        self.price.saturating_mul(u128::from(periods))
+    }
+}
+
+impl SlashingPolicy {
+    /// Validate policy constraints
+    pub fn validate(&self) -> Result<(), BotStateError> {
+        if self.slash_bps > 10_000 {
+            return Err(BotStateError::SlashOutOfRange(self.slash_bps));
+        }
+        if self.min_accuracy_bps > 10_000 {
+            return Err(BotStateError::AccuracyThresholdOutOfRange(self.min_accuracy_bps));
+        }
+        if self.max_signal_interval_ms == Some(0) {
+            return Err(BotStateError::ZeroSignalInterval);
+        }
+        Ok(())
+    }
+
+    /// Amount to slash from `stake` for one violation
+    pub fn slash_amount(&self, stake: Amount) -> Amount {
+        Amount::from_attos(u128::from(stake) * u128::from(self.slash_bps) / 10_000)
+    }
+
+    /// Check whether `metrics` fall below the accuracy threshold, once enough
+    /// predictions were resolved in the window
+    pub fn is_accuracy_violated(&self, metrics: &AccuracyMetrics) -> bool {
+        metrics.total_predictions >= self.min_predictions
+            && metrics.total_predictions > 0
+            && metrics.directional_accuracy_bps < self.min_accuracy_bps
+    }
+
+    /// Get the next missed signal deadline at `now`, if any
+    ///
+    /// A signal is due `max_signal_interval_ms` after the latest signal, or after the
+    /// previous missed deadline so each missed interval is slashed only once.
+    pub fn missed_deadline(&self, latest_signal: u64, last_slashed: u64, now: u64) -> Option<u64> {
+        let interval = self.max_signal_interval_ms?;
+        let deadline = latest_signal.max(last_slashed).saturating_add(interval);
+        (now > deadline).then_some(deadline)
+    }
+}
+
 impl Signal {
+    /// Check that an asset symbol is non-empty, short and made of uppercase letters
+    /// and digits
+    pub fn validate_asset(asset: &str) -> Result<(), BotStateError> {
+        let is_valid = !asset.is_empty()
+            && asset.len() <= MAX_ASSET_LEN
+            && asset.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+        if !is_valid {
+            return Err(BotStateError::InvalidAsset(asset.to_string()));
+        }
+        Ok(())
+    }
+
     /// Validate signal constraints
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self) -> Result<(), BotStateError> {
+        Self::validate_asset(&self.asset)?;
+
         if self.confidence_bps > 10_000 {
-            return Err("Confidence must be between 0 and 10000 basis points".to_string());
+            return Err(BotStateError::ConfidenceOutOfRange(self.confidence_bps));
         }
 
         if self.predicted_price_micro == 0 {
-            return Err("Predicted price must be positive".to_string());
+            return Err(BotStateError::ZeroPredictedPrice);
+        }
+
+        if self.reference_price_micro == 0 {
+            return Err(BotStateError::ZeroReferencePrice);
         }
 
         if self.reasoning.len() > 512 {
-            return Err("Reasoning must be <= 512 characters".to_string());
+            return Err(BotStateError::ReasoningTooLong(self.reasoning.len()));
         }
 
         if self.timestamp == 0 {
-            return Err("Timestamp must be non-zero".to_string());
+            return Err(BotStateError::ZeroTimestamp);
+        }
+
+        if self.target_timestamp <= self.timestamp {
+            return Err(BotStateError::TargetNotAfterTimestamp);
         }
 
         Ok(())
     }
 
-    /// Check if this signal's directional prediction was correct
-    pub fn is_directionally_correct(&self, previous_price_micro: u64) -> Option<bool> {
+    /// Check if the signal may be resolved at `now` (milliseconds), i.e. within
+    /// `grace_ms` of its target timestamp
+    pub fn is_resolvable_at(&self, now: u64, grace_ms: u64) -> bool {
+        now >= self.target_timestamp.saturating_sub(grace_ms)
+            && now <= self.target_timestamp.saturating_add(grace_ms)
+    }
+
+    /// Check if an unresolved signal has passed its target timestamp at `now` (milliseconds)
+    pub fn is_overdue_at(&self, now: u64) -> bool {
+        self.actual_price_micro.is_none() && now > self.target_timestamp
+    }
+
+    /// Check if this signal's directional prediction was correct,
+    /// comparing the actual price against the reference price at issue time
+    ///
+    /// A Hold is correct if the price moved less than `hold_band_bps` basis points.
+    pub fn is_directionally_correct(&self, hold_band_bps: u64) -> Option<bool> {
+        let previous_price_micro = self.reference_price_micro;
         self.actual_price_micro.map(|actual| {
             match self.action {
                 Action::Buy => actual > previous_price_micro,
                 Action::Sell => actual < previous_price_micro,
                 Action::Hold => {
-                    // Within 2%: |actual - prev| / prev < 0.02
-                    let diff = if actual > previous_price_micro {
-                        actual - previous_price_micro
-                    } else {
-                        previous_price_micro - actual
-                    };
-                    // diff / prev < 0.02 => diff < prev * 0.02
-                    diff < previous_price_micro / 50 // 1/50 = 0.02 = 2%
+                    // Within the band: |actual - prev| / prev < band_bps / 10000
+                    let diff = actual.abs_diff(previous_price_micro);
+                    // diff / prev < band => diff * 10000 < prev * band_bps
+                    u128::from(diff) * 10_000
+                        < u128::from(previous_price_micro) * u128::from(hold_band_bps)
                 },
             }
         })
@@ -127,34 +968,121 @@ impl Signal {
 }
 
 impl AccuracyMetrics {
-    /// Update metrics with a new resolved signal
-    pub fn update(&mut self, signal: &Signal, previous_price_micro: u64, current_timestamp: u64) {
+    /// Update metrics with a new resolved signal, scoring Hold signals against
+    /// `hold_band_bps`
+    pub fn update(&mut self, signal: &Signal, current_timestamp: u64, hold_band_bps: u64) {
         if let Some(actual) = signal.actual_price_micro {
-            self.total_predictions += 1;
+            self.advance_to(current_timestamp);
 
-            // Update directional accuracy
-            if let Some(correct) = signal.is_directionally_correct(previous_price_micro) {
+            let correct = signal.is_directionally_correct(hold_band_bps) == Some(true);
+            let signed_error = signal.predicted_price_micro as i128 - actual as i128;
+            let absolute_error = signed_error.unsigned_abs();
+            let squared_error = absolute_error * absolute_error;
+
+            if !self.buckets.is_empty() {
+                let index = (self.current_bucket % self.buckets.len() as u64) as usize;
+                let bucket = &mut self.buckets[index];
+                bucket.total_predictions += 1;
+                bucket.squared_error_sum += squared_error;
+                bucket.absolute_error_sum += absolute_error;
+                bucket.signed_error_sum += signed_error;
                 if correct {
-                    self.correct_predictions += 1;
+                    bucket.correct_predictions += 1;
                 }
             }
+            self.total_predictions += 1;
+            self.squared_error_sum += squared_error;
+            self.absolute_error_sum += absolute_error;
+            self.signed_error_sum += signed_error;
+            if correct {
+                self.correct_predictions += 1;
+            }
+            self.recompute();
 
-            // Calculate directional accuracy in basis points (0-10000)
-            self.directional_accuracy_bps = if self.total_predictions > 0 {
-                (self.correct_predictions * 10_000) / self.total_predictions
-            } else {
-                0
-            };
+            self.last_updated = current_timestamp;
+        }
+    }
 
-            // Update RMSE in micro-USD (simplified: single error, not running average)
-            let error_micro = if signal.predicted_price_micro > actual {
-                signal.predicted_price_micro - actual
-            } else {
-                actual - signal.predicted_price_micro
-            };
-            self.rmse_micro = error_micro;
+    /// Count a commitment that was never revealed as a wrong call at `current_timestamp`
+    pub fn record_miss(&mut self, current_timestamp: u64) {
+        self.advance_to(current_timestamp);
+        if !self.buckets.is_empty() {
+            let index = (self.current_bucket % self.buckets.len() as u64) as usize;
+            let bucket = &mut self.buckets[index];
+            bucket.total_predictions += 1;
+            bucket.missed_predictions += 1;
+        }
+        self.total_predictions += 1;
+        self.missed_predictions += 1;
+        self.recompute();
+        self.last_updated = current_timestamp;
+    }
 
-            self.last_updated = current_timestamp;
+    /// Move the window forward to `current_timestamp` (milliseconds),
+    /// dropping the buckets that fell out of it
+    ///
+    /// At most one bucket per slot of the ring buffer is cleared, so this is
+    /// O(1) per call. The all-time window has no buckets and never expires.
+    pub fn advance_to(&mut self, current_timestamp: u64) {
+        let bucket_count = self.buckets.len() as u64;
+        if bucket_count == 0 {
+            return;
+        }
+
+        let bucket = current_timestamp / self.window.bucket_duration_ms();
+        if bucket <= self.current_bucket {
+            return;
+        }
+
+        let expired = (bucket - self.current_bucket).min(bucket_count);
+        for step in 1..=expired {
+            let index = ((self.current_bucket + step) % bucket_count) as usize;
+            let bucket = std::mem::take(&mut self.buckets[index]);
+            self.total_predictions -= bucket.total_predictions;
+            self.correct_predictions -= bucket.correct_predictions;
+            self.missed_predictions -= bucket.missed_predictions;
+            self.squared_error_sum -= bucket.squared_error_sum;
+            self.absolute_error_sum -= bucket.absolute_error_sum;
+            self.signed_error_sum -= bucket.signed_error_sum;
+        }
+        self.current_bucket = bucket;
+        self.recompute();
+    }
+
+    /// Copy of the metrics without the per-bucket ring buffer, for publishing
+    pub fn summary(&self) -> Self {
+        Self {
+            buckets: Vec::new(),
+            ..self.clone()
+        }
+    }
+
+    /// Recompute derived ratios and error statistics from the window totals
+    fn recompute(&mut self) {
+        let count = self.total_predictions;
+        if count == 0 {
+            self.directional_accuracy_bps = 0;
+            self.rmse_micro = 0;
+            self.mae_micro = 0;
+            self.bias_micro = 0;
+            return;
         }
+
+        // Calculate directional accuracy in basis points (0-10000)
+        self.directional_accuracy_bps = (self.correct_predictions * 10_000) / count;
+
+        // Missed reveals have no price to measure an error against
+        let count = count - self.missed_predictions;
+        if count == 0 {
+            self.rmse_micro = 0;
+            self.mae_micro = 0;
+            self.bias_micro = 0;
+            return;
+        }
+
+        // RMSE = sqrt(sum(e^2) / n), MAE = sum(|e|) / n, bias = sum(e) / n
+        self.rmse_micro = (self.squared_error_sum / count as u128).isqrt() as u64;
+        self.mae_micro = (self.absolute_error_sum / count as u128) as u64;
+        self.bias_micro = (self.signed_error_sum / count as i128) as i64;
     }
 }
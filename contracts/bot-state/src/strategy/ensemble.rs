@@ -0,0 +1,229 @@
+use std::rc::Rc;
+
+use bot_state::{Action, Signal};
+
+use super::{truncate, MarketSnapshot, PredictionStrategy, StrategyError, MAX_REASONING_BYTES};
+
+/// Publishes the consensus of several sub-strategies run on the same snapshot
+///
+/// Each sub-signal votes for its action with its confidence as weight; the action with
+/// the most weight wins (Hold on a tie). The predicted price is the confidence-weighted
+/// median of the sub-signals' prices. Sub-strategies that fail are left out of the vote
+/// and listed in the reasoning.
+pub struct EnsembleStrategy {
+    /// Configured sub-strategies by name, `None` if no such strategy is registered
+    members: Vec<(String, Option<Rc<dyn PredictionStrategy>>)>,
+}
+
+impl EnsembleStrategy {
+    pub fn new(members: Vec<(String, Option<Rc<dyn PredictionStrategy>>)>) -> Self {
+        EnsembleStrategy { members }
+    }
+}
+
+impl PredictionStrategy for EnsembleStrategy {
+    fn name(&self) -> &str {
+        "ensemble"
+    }
+
+    fn predict(&self, snapshot: &MarketSnapshot) -> Result<Signal, StrategyError> {
+        let mut signals = Vec::new();
+        let mut failures = Vec::new();
+        for (name, member) in &self.members {
+            let result = match member {
+                Some(strategy) => strategy.predict(snapshot).map_err(|e| e.to_string()),
+                None => Err(format!("Unknown strategy: {}", name)),
+            };
+            match result {
+                Ok(signal) => signals.push((name.as_str(), signal)),
+                Err(error) => failures.push(format!("{} failed: {}", name, error)),
+            }
+        }
+        if signals.is_empty() {
+            return Err(StrategyError::NoSignals(failures.join("; ")));
+        }
+
+        let weight = |action: Action| -> u64 {
+            signals
+                .iter()
+                .filter(|(_, signal)| signal.action == action)
+                .map(|(_, signal)| signal.confidence_bps)
+                .sum()
+        };
+        let (buy, sell, hold) = (weight(Action::Buy), weight(Action::Sell), weight(Action::Hold));
+        let (action, action_weight) = if buy > sell && buy > hold {
+            (Action::Buy, buy)
+        } else if sell > buy && sell > hold {
+            (Action::Sell, sell)
+        } else {
+            (Action::Hold, hold)
+        };
+
+        let prices = signals
+            .iter()
+            .map(|(_, signal)| (signal.predicted_price_micro, signal.confidence_bps))
+            .collect();
+
+        let mut parts = vec![format!(
+            "Ensemble of {}: Buy {} / Sell {} / Hold {} bps",
+            signals.len(),
+            buy,
+            sell,
+            hold
+        )];
+        parts.extend(signals.iter().map(|(name, signal)| {
+            format!(
+                "{} {:?} ${:.2} at {}%: {}",
+                name,
+                signal.action,
+                signal.predicted_price_micro as f64 / 1_000_000.0,
+                signal.confidence_bps / 100,
+                signal.reasoning
+            )
+        }));
+        parts.extend(failures);
+
+        Ok(Signal {
+            id: 0, // Assigned by the contract
+            timestamp: snapshot.timestamp,
+            target_timestamp: snapshot.target_timestamp()?,
+            asset: snapshot.symbol.clone(),
+            action,
+            reference_price_micro: (snapshot.current_price * 1_000_000.0) as u64,
+            predicted_price_micro: weighted_median(prices),
+            // Dissenting sub-signals count as zero confidence
+            confidence_bps: action_weight / signals.len() as u64,
+            reasoning: truncate(parts.join(" | "), MAX_REASONING_BYTES),
+            actual_price_micro: None,
+            commitment: None,
+        })
+    }
+}
+
+/// Smallest value whose cumulative weight reaches half the total (equal weights if all
+/// weights are zero)
+fn weighted_median(mut values: Vec<(u64, u64)>) -> u64 {
+    if values.iter().all(|(_, weight)| *weight == 0) {
+        values.iter_mut().for_each(|(_, weight)| *weight = 1);
+    }
+    values.sort_unstable();
+    let total: u64 = values.iter().map(|(_, weight)| weight).sum();
+    let mut cumulative = 0;
+    for (value, weight) in &values {
+        cumulative += weight;
+        if cumulative * 2 >= total {
+            return *value;
+        }
+    }
+    unreachable!("Cumulative weight reaches the total")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use bot_state::{Action, Signal};
+
+    use super::{weighted_median, EnsembleStrategy};
+    use crate::strategy::{MarketSnapshot, PredictionStrategy, StrategyError};
+
+    /// Always predicts the same action, price and confidence
+    struct Fixed(Action, u64, u64);
+
+    impl PredictionStrategy for Fixed {
+        fn name(&self) -> &str {
+            "fixed"
+        }
+
+        fn predict(&self, snapshot: &MarketSnapshot) -> Result<Signal, StrategyError> {
+            let Fixed(action, predicted_price_micro, confidence_bps) = *self;
+            Ok(Signal {
+                id: 0,
+                timestamp: snapshot.timestamp,
+                target_timestamp: snapshot.target_timestamp()?,
+                asset: snapshot.symbol.clone(),
+                action,
+                reference_price_micro: 3_500_000_000,
+                predicted_price_micro,
+                confidence_bps,
+                reasoning: format!("{:?} reasons", action),
+                actual_price_micro: None,
+                commitment: None,
+            })
+        }
+    }
+
+    fn ensemble(members: Vec<(&str, Option<Fixed>)>) -> EnsembleStrategy {
+        EnsembleStrategy::new(
+            members
+                .into_iter()
+                .map(|(name, member)| {
+                    let member = member.map(|fixed| Rc::new(fixed) as Rc<dyn PredictionStrategy>);
+                    (name.to_string(), member)
+                })
+                .collect(),
+        )
+    }
+
+    fn snapshot() -> MarketSnapshot {
+        MarketSnapshot {
+            symbol: "ETHUSDT".to_string(),
+            timestamp: 1000,
+            horizon_ms: 3_600_000,
+            interval: "1h".to_string(),
+            current_price: 3500.0,
+            change_24h: 0.0,
+            volume_24h: 0.0,
+            price_history: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_ensemble_vote() {
+        let strategy = ensemble(vec![
+            ("a", Some(Fixed(Action::Buy, 3_600_000_000, 6000))),
+            ("b", Some(Fixed(Action::Sell, 3_400_000_000, 8000))),
+            ("c", Some(Fixed(Action::Buy, 3_550_000_000, 4000))),
+            ("d", None),
+        ]);
+
+        let signal = strategy.predict(&snapshot()).unwrap();
+        // Buy weighs 10000 against Sell's 8000
+        assert_eq!(signal.action, Action::Buy);
+        assert_eq!(signal.confidence_bps, 10_000 / 3);
+        // Sorted prices weigh 8000, 4000 and 6000: half of the total is reached at 3550
+        assert_eq!(signal.predicted_price_micro, 3_550_000_000);
+        assert_eq!(signal.reference_price_micro, 3_500_000_000);
+        assert!(signal.reasoning.starts_with("Ensemble of 3: Buy 10000 / Sell 8000 / Hold 0 bps"));
+        assert!(signal.reasoning.contains("b Sell $3400.00 at 80%: Sell reasons"));
+        assert!(signal.reasoning.ends_with("d failed: Unknown strategy: d"));
+    }
+
+    #[test]
+    fn test_ensemble_tie_holds() {
+        let strategy = ensemble(vec![
+            ("a", Some(Fixed(Action::Buy, 3_600_000_000, 7000))),
+            ("b", Some(Fixed(Action::Sell, 3_400_000_000, 7000))),
+        ]);
+
+        let signal = strategy.predict(&snapshot()).unwrap();
+        assert_eq!(signal.action, Action::Hold);
+        assert_eq!(signal.confidence_bps, 0);
+        assert_eq!(signal.predicted_price_micro, 3_400_000_000);
+    }
+
+    #[test]
+    fn test_ensemble_without_signals() {
+        let strategy = ensemble(vec![("missing", None)]);
+        assert_eq!(
+            strategy.predict(&snapshot()),
+            Err(StrategyError::NoSignals("missing failed: Unknown strategy: missing".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_weighted_median() {
+        assert_eq!(weighted_median(vec![(100, 8000), (110, 1000), (120, 2000)]), 100);
+        assert_eq!(weighted_median(vec![(120, 0), (100, 0), (110, 0)]), 110);
+    }
+}
@@ -0,0 +1,639 @@
+use std::sync::Arc;
+
+use linera_sdk::http;
+use serde::Deserialize;
+
+use bot_state::{Action, BotParameters, Signal};
+
+use super::{
+    truncate, HttpClient, MarketSnapshot, PredictionStrategy, StrategyError, MAX_REASONING_BYTES,
+};
+
+/// DeepSeek V3 model used by the "deepseek" strategy
+const DEEPSEEK_MODEL: &str = "deepseek-chat";
+
+/// Qwen 3 Coder 480B on Vertex AI, used by the "qwen-vertex" strategy
+const QWEN_VERTEX_MODEL: &str = "qwen/qwen3-coder-480b-a35b-instruct-maas";
+
+/// GPT OSS 120B on Vertex AI, used by the "gpt-oss-vertex" strategy
+const GPT_OSS_VERTEX_MODEL: &str = "openai/gpt-oss-120b-maas";
+
+/// How an endpoint is asked for structured output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseFormat {
+    /// `json_schema` with the strict answer schema
+    JsonSchema,
+    /// `json_object`, for providers without JSON schema support (the prompt shows the schema)
+    JsonObject,
+}
+
+/// OpenAI-compatible chat completion endpoint reached through the proxy
+#[derive(Debug, PartialEq)]
+pub struct LlmEndpoint {
+    /// Provider name used in error messages
+    pub provider: &'static str,
+    /// Path of the endpoint below the proxy URL
+    pub path: &'static str,
+    pub model: String,
+    pub response_format: ResponseFormat,
+}
+
+impl LlmEndpoint {
+    /// Endpoint of an LLM strategy, or `None` if `strategy` is not LLM-backed
+    pub fn for_strategy(strategy: &str, parameters: &BotParameters) -> Option<Self> {
+        let (provider, path, model, response_format) = match strategy {
+            "gemma" => (
+                "inference.net",
+                "inference/chat/completions",
+                parameters.model_id.as_str(),
+                ResponseFormat::JsonSchema,
+            ),
+            "deepseek" => (
+                "DeepSeek",
+                "deepseek/chat/completions",
+                DEEPSEEK_MODEL,
+                ResponseFormat::JsonObject,
+            ),
+            "qwen-vertex" => (
+                "Vertex AI",
+                "vertex/us-south1/chat/completions",
+                QWEN_VERTEX_MODEL,
+                ResponseFormat::JsonObject,
+            ),
+            "gpt-oss-vertex" => (
+                "Vertex AI",
+                "vertex/global/chat/completions",
+                GPT_OSS_VERTEX_MODEL,
+                ResponseFormat::JsonObject,
+            ),
+            _ => return None,
+        };
+        Some(LlmEndpoint {
+            provider,
+            path,
+            model: model.to_string(),
+            response_format,
+        })
+    }
+
+    /// `response_format` field of the chat completion request
+    fn response_format(&self) -> serde_json::Value {
+        match self.response_format {
+            ResponseFormat::JsonSchema => serde_json::json!({
+                "type": "json_schema",
+                "json_schema": {
+                    "name": "trading_signal",
+                    "strict": true,
+                    "schema": {
+                        "type": "object",
+                        "properties": {
+                            "action": {"type": "string", "enum": ["BUY", "SELL", "HOLD"]},
+                            "price": {"type": "number"},
+                            "confidence": {"type": "integer", "minimum": 0, "maximum": 100},
+                            "reasoning": {"type": "string"}
+                        },
+                        "required": ["action", "price", "confidence", "reasoning"],
+                        "additionalProperties": false
+                    }
+                }
+            }),
+            ResponseFormat::JsonObject => serde_json::json!({"type": "json_object"}),
+        }
+    }
+}
+
+/// Asks an LLM for a prediction through the HTTP proxy
+pub struct LlmStrategy {
+    name: &'static str,
+    endpoint: LlmEndpoint,
+    proxy_url: String,
+    http: Arc<dyn HttpClient>,
+    /// Forwarded by the proxy to the provider (a GCP access token for Vertex AI)
+    api_key: Option<String>,
+}
+
+impl LlmStrategy {
+    pub fn new(
+        name: &'static str,
+        endpoint: LlmEndpoint,
+        proxy_url: &str,
+        http: Arc<dyn HttpClient>,
+        api_key: Option<String>,
+    ) -> Self {
+        LlmStrategy {
+            name,
+            endpoint,
+            proxy_url: proxy_url.to_string(),
+            http,
+            api_key,
+        }
+    }
+
+    /// Build prompt with market data
+    fn build_prompt(&self, data: &MarketSnapshot) -> String {
+        let ohlc: Vec<String> = data.price_history.iter().enumerate().map(|(i, candle)| {
+            let candles_ago = data.price_history.len() - i;
+            format!(
+                "{} ago: O=${:.2} H=${:.2} L=${:.2} C=${:.2} V={:.1}k",
+                candle_age(candles_ago, &data.interval),
+                candle.open, candle.high, candle.low, candle.close, candle.volume / 1000.0
+            )
+        }).collect();
+
+        format!(
+            r#"Current {} Market Data ({} candles of {}):
+- Current Price: ${:.2}
+- 24h Change: {:.2}%
+- 24h Volume: ${}
+
+Complete OHLC Candlesticks:
+{}
+
+Task: Predict {} price movement in the next {} based on technical analysis.
+
+Respond with your final answer as a single JSON object with exactly these fields:
+{{"action": "BUY" | "SELL" | "HOLD", "price": <predicted price in USD, e.g. 3575.50>, "confidence": <integer 0-100>, "reasoning": "<max 200 chars explaining your technical analysis>"}}"#,
+            base_asset(&data.symbol),
+            data.price_history.len(),
+            data.interval,
+            data.current_price,
+            data.change_24h,
+            data.volume_24h as u64,
+            ohlc.join("\n"),
+            base_asset(&data.symbol),
+            describe_horizon(data.horizon_ms)
+        )
+    }
+
+    /// Parse LLM response into Signal
+    ///
+    /// The JSON answer is parsed strictly; answer lines (`ACTION:`, `PRICE:`, `CONFIDENCE:`,
+    /// optional `REASONING:`) are accepted as a fallback for models ignoring the format.
+    fn parse_llm_response(
+        &self,
+        content: &str,
+        data: &MarketSnapshot,
+    ) -> Result<Signal, StrategyError> {
+        let answer = match parse_json_answer(content) {
+            Ok(answer) => answer,
+            Err(json_error) => parse_line_answer(content).map_err(|lines_error| {
+                StrategyError::UnparseableResponse {
+                    json: json_error,
+                    lines: lines_error,
+                }
+            })?,
+        };
+
+        // Convert to contract format (micro-USD and basis points)
+        Ok(Signal {
+            id: 0, // Assigned by the contract
+            timestamp: data.timestamp,
+            target_timestamp: data.target_timestamp()?,
+            asset: data.symbol.clone(),
+            action: answer.action.into(),
+            reference_price_micro: (data.current_price * 1_000_000.0) as u64,
+            predicted_price_micro: (answer.price * 1_000_000.0) as u64,
+            confidence_bps: answer.confidence * 100, // Convert 0-100 to basis points
+            reasoning: truncate(answer.reasoning.trim().to_string(), MAX_REASONING_BYTES),
+            actual_price_micro: None,
+            commitment: None,
+        })
+    }
+}
+
+impl PredictionStrategy for LlmStrategy {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    /// Call the OpenAI-compatible chat completion endpoint for an LLM prediction
+    fn predict(&self, market_data: &MarketSnapshot) -> Result<Signal, StrategyError> {
+        let api_key = self
+            .api_key
+            .as_ref()
+            .ok_or(StrategyError::MissingApiKey(self.endpoint.provider))?;
+
+        let system_prompt = format!("You are an expert cryptocurrency trader specializing in {} price predictions.\nAnalyze market data using technical analysis and market psychology to provide clear trading signals.\nIMPORTANT: You MUST provide your final answer as the JSON object specified.", base_asset(&market_data.symbol));
+
+        let user_prompt = self.build_prompt(market_data);
+
+        let body = serde_json::json!({
+            "model": self.endpoint.model,
+            "messages": [
+                {"role": "system", "content": system_prompt},
+                {"role": "user", "content": user_prompt}
+            ],
+            "temperature": 0.7,
+            "max_tokens": 1024,
+            "response_format": self.endpoint.response_format()
+        });
+
+        // Using localhost proxy to bypass HTTP authorization restrictions
+        // Proxy transparently forwards the Authorization header to the provider
+        let mut request = http::Request::post(
+            format!("{}/{}", self.proxy_url, self.endpoint.path),
+            serde_json::to_vec(&body).map_err(|e| StrategyError::InvalidRequest(e.to_string()))?
+        );
+        request.headers = vec![
+            http::Header::new("Content-Type", b"application/json"),
+            http::Header::new("Authorization", format!("Bearer {}", api_key)),
+        ];
+
+        let response = self.http.send(request);
+
+        if response.status != 200 {
+            return Err(StrategyError::Api {
+                provider: self.endpoint.provider,
+                status: response.status,
+                body: String::from_utf8_lossy(&response.body).into_owned(),
+            });
+        }
+
+        let result: serde_json::Value = serde_json::from_slice(&response.body)
+            .map_err(|e| StrategyError::InvalidResponse(e.to_string()))?;
+
+        // GPT OSS answers in `reasoning_content` instead of `content`
+        let message = &result["choices"][0]["message"];
+        let content = message["content"]
+            .as_str()
+            .filter(|content| !content.is_empty())
+            .or_else(|| message["reasoning_content"].as_str())
+            .ok_or(StrategyError::EmptyResponse)?;
+
+        self.parse_llm_response(content, market_data)
+    }
+}
+
+/// Name of the traded asset for LLM prompts, e.g. "ETH" for "ETHUSDT"
+fn base_asset(symbol: &str) -> &str {
+    symbol.strip_suffix("USDT").filter(|base| !base.is_empty()).unwrap_or(symbol)
+}
+
+/// Age of a candle `candles_ago` intervals back, e.g. "5h" for 5 candles of "1h"
+fn candle_age(candles_ago: usize, interval: &str) -> String {
+    let unit_start = interval.find(|c: char| !c.is_ascii_digit()).unwrap_or(interval.len());
+    let (count, unit) = interval.split_at(unit_start);
+    let count = count.parse::<usize>().unwrap_or(1);
+    format!("{}{}", candles_ago * count, unit)
+}
+
+/// Describe a prediction horizon for the LLM prompt, e.g. "hour" or "4 hours"
+fn describe_horizon(horizon_ms: u64) -> String {
+    const MINUTE_MS: u64 = 60_000;
+    const HOUR_MS: u64 = 60 * MINUTE_MS;
+    match horizon_ms {
+        HOUR_MS => "hour".to_string(),
+        ms if ms % HOUR_MS == 0 => format!("{} hours", ms / HOUR_MS),
+        ms => format!("{} minutes", ms / MINUTE_MS),
+    }
+}
+
+/// Action of an LLM answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+enum AnswerAction {
+    Buy,
+    Sell,
+    Hold,
+}
+
+impl From<AnswerAction> for Action {
+    fn from(action: AnswerAction) -> Self {
+        match action {
+            AnswerAction::Buy => Action::Buy,
+            AnswerAction::Sell => Action::Sell,
+            AnswerAction::Hold => Action::Hold,
+        }
+    }
+}
+
+/// Final answer of an LLM, matching the requested JSON schema
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Answer {
+    action: AnswerAction,
+    /// Predicted price in USD
+    price: f64,
+    /// Confidence from 0 to 100
+    confidence: u64,
+    reasoning: String,
+}
+
+impl Answer {
+    /// Reject values the schema cannot express
+    fn check(self) -> Result<Self, String> {
+        if !self.price.is_finite() || self.price <= 0.0 {
+            return Err(format!("price must be positive, got {}", self.price));
+        }
+        if self.confidence > 100 {
+            return Err(format!("confidence must be at most 100, got {}", self.confidence));
+        }
+        Ok(self)
+    }
+}
+
+/// Parse the JSON answer, either the whole content or the last outermost `{...}` in it
+/// (for answers wrapped in prose or a code fence)
+fn parse_json_answer(content: &str) -> Result<Answer, String> {
+    let content = content.trim();
+    let json = match serde_json::from_str::<Answer>(content) {
+        Ok(answer) => return answer.check(),
+        Err(error) => last_json_object(content).ok_or_else(|| error.to_string())?,
+    };
+    serde_json::from_str::<Answer>(json)
+        .map_err(|error| error.to_string())?
+        .check()
+}
+
+/// Last balanced top-level `{...}` in `text`, skipping braces inside JSON strings
+fn last_json_object(text: &str) -> Option<&str> {
+    let mut last = None;
+    let mut start = 0;
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, c) in text.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' if depth > 0 => in_string = true,
+            '{' => {
+                if depth == 0 {
+                    start = index;
+                }
+                depth += 1;
+            }
+            '}' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    last = Some(&text[start..=index]);
+                }
+            }
+            _ => {}
+        }
+    }
+    last
+}
+
+/// Parse `ACTION:`, `PRICE:`, `CONFIDENCE:` and `REASONING:` lines (the last of each wins)
+fn parse_line_answer(content: &str) -> Result<Answer, String> {
+    let mut action = None;
+    let mut price = None;
+    let mut confidence = None;
+    let mut reasoning = "No reasoning provided".to_string();
+
+    for line in content.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim().trim_matches(|c: char| c == '*' || c == '[' || c == ']').trim();
+        match key.trim().trim_matches('*') {
+            "ACTION" => {
+                action = Some(match value.to_uppercase().as_str() {
+                    "BUY" => AnswerAction::Buy,
+                    "SELL" => AnswerAction::Sell,
+                    "HOLD" => AnswerAction::Hold,
+                    _ => return Err(format!("unknown action {:?}", value)),
+                });
+            }
+            "PRICE" => {
+                let price_str = value.replace(['$', ','], "");
+                price = Some(
+                    price_str
+                        .parse::<f64>()
+                        .map_err(|_| format!("invalid price {:?}", value))?,
+                );
+            }
+            "CONFIDENCE" => {
+                let conf_str = value.trim_end_matches('%').trim();
+                confidence = Some(
+                    conf_str
+                        .parse::<u64>()
+                        .map_err(|_| format!("invalid confidence {:?}", value))?,
+                );
+            }
+            "REASONING" => reasoning = value.to_string(),
+            _ => {}
+        }
+    }
+
+    Answer {
+        action: action.ok_or("missing ACTION line")?,
+        price: price.ok_or("missing PRICE line")?,
+        confidence: confidence.ok_or("missing CONFIDENCE line")?,
+        reasoning,
+    }
+    .check()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use linera_sdk::http;
+    use serde_json::json;
+
+    use bot_state::{Action, BotParameters};
+
+    use super::{LlmEndpoint, LlmStrategy, ResponseFormat};
+    use crate::strategy::{
+        HttpClient, MarketSnapshot, PredictionStrategy, PricePoint, StrategyError,
+    };
+
+    /// Answers every request with a fixed response and records the requests
+    struct FakeHttp {
+        response: http::Response,
+        requests: Mutex<Vec<http::Request>>,
+    }
+
+    impl HttpClient for FakeHttp {
+        fn send(&self, request: http::Request) -> http::Response {
+            self.requests.lock().unwrap().push(request);
+            self.response.clone()
+        }
+    }
+
+    fn llm_strategy(name: &'static str, response: http::Response) -> (LlmStrategy, Arc<FakeHttp>) {
+        let http = Arc::new(FakeHttp {
+            response,
+            requests: Mutex::new(Vec::new()),
+        });
+        let endpoint = LlmEndpoint::for_strategy(name, &BotParameters::default()).unwrap();
+        let strategy = LlmStrategy::new(
+            name,
+            endpoint,
+            "http://proxy",
+            http.clone(),
+            Some("key".to_string()),
+        );
+        (strategy, http)
+    }
+
+    fn completion(message: serde_json::Value) -> http::Response {
+        http::Response::ok(serde_json::to_vec(&json!({"choices": [{"message": message}]})).unwrap())
+    }
+
+    fn snapshot() -> MarketSnapshot {
+        MarketSnapshot {
+            symbol: "ETHUSDT".to_string(),
+            timestamp: 1000,
+            horizon_ms: 3_600_000,
+            interval: "1h".to_string(),
+            current_price: 3500.0,
+            change_24h: 1.5,
+            volume_24h: 1_000_000.0,
+            price_history: vec![PricePoint {
+                timestamp: 0,
+                open: 3490.0,
+                high: 3510.0,
+                low: 3480.0,
+                close: 3500.0,
+                volume: 2000.0,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_llm_endpoints() {
+        let parameters = BotParameters {
+            model_id: "custom-model".to_string(),
+            ..BotParameters::default()
+        };
+
+        let gemma = LlmEndpoint::for_strategy("gemma", &parameters).unwrap();
+        assert_eq!(gemma.path, "inference/chat/completions");
+        assert_eq!(gemma.model, "custom-model");
+        assert_eq!(gemma.response_format, ResponseFormat::JsonSchema);
+
+        let deepseek = LlmEndpoint::for_strategy("deepseek", &parameters).unwrap();
+        assert_eq!(deepseek.path, "deepseek/chat/completions");
+        assert_eq!(deepseek.model, "deepseek-chat");
+        assert_eq!(deepseek.response_format, ResponseFormat::JsonObject);
+
+        let qwen = LlmEndpoint::for_strategy("qwen-vertex", &parameters).unwrap();
+        assert_eq!(qwen.path, "vertex/us-south1/chat/completions");
+
+        let gpt_oss = LlmEndpoint::for_strategy("gpt-oss-vertex", &parameters).unwrap();
+        assert_eq!(gpt_oss.path, "vertex/global/chat/completions");
+        assert_eq!(gpt_oss.provider, "Vertex AI");
+
+        assert_eq!(LlmEndpoint::for_strategy("simple-ma", &parameters), None);
+    }
+
+    #[test]
+    fn test_llm_prediction() {
+        let content =
+            r#"{"action": "BUY", "price": 3600.5, "confidence": 80, "reasoning": "Uptrend"}"#;
+        let (strategy, http) = llm_strategy("gemma", completion(json!({"content": content})));
+
+        let signal = strategy.predict(&snapshot()).unwrap();
+        let requests = http.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url, "http://proxy/inference/chat/completions");
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["response_format"]["type"], "json_schema");
+        assert_eq!(body["response_format"]["json_schema"]["strict"], true);
+
+        assert_eq!(signal.asset, "ETHUSDT");
+        assert_eq!(signal.action, Action::Buy);
+        assert_eq!(signal.target_timestamp, 3_601_000);
+        assert_eq!(signal.reference_price_micro, 3_500_000_000);
+        assert_eq!(signal.predicted_price_micro, 3_600_500_000);
+        assert_eq!(signal.confidence_bps, 8000);
+        assert_eq!(signal.reasoning, "Uptrend");
+    }
+
+    #[test]
+    fn test_llm_prediction_from_reasoning_content() {
+        let reasoning = "Thinking...\n```json\n{\"action\": \"SELL\", \"price\": 3400, \
+                         \"confidence\": 60, \"reasoning\": \"Rejected at resistance\"}\n```";
+        let message = json!({"content": "", "reasoning_content": reasoning});
+        let (strategy, _) = llm_strategy("gpt-oss-vertex", completion(message));
+
+        let signal = strategy.predict(&snapshot()).unwrap();
+        assert_eq!(signal.action, Action::Sell);
+        assert_eq!(signal.predicted_price_micro, 3_400_000_000);
+        assert_eq!(signal.confidence_bps, 6000);
+        assert_eq!(signal.reasoning, "Rejected at resistance");
+    }
+
+    #[test]
+    fn test_llm_prediction_from_wrapped_json() {
+        // Braces in the reasoning and in the prose around the answer
+        let content = "Options: {BUY, SELL, HOLD}\n{\"action\": \"BUY\", \"price\": 3600, \
+                       \"confidence\": 70, \"reasoning\": \"Breakout {4h} \\\"confirmed\\\"\"} \
+                       Done.";
+        let (strategy, _) = llm_strategy("deepseek", completion(json!({"content": content})));
+
+        let signal = strategy.predict(&snapshot()).unwrap();
+        assert_eq!(signal.action, Action::Buy);
+        assert_eq!(signal.reasoning, "Breakout {4h} \"confirmed\"");
+
+        // Reasoning is cut to the byte limit of `Signal::validate`
+        let reasoning = "é".repeat(300);
+        let answer = json!({"action": "HOLD", "price": 3500, "confidence": 50, "reasoning": reasoning});
+        let (strategy, _) =
+            llm_strategy("deepseek", completion(json!({"content": answer.to_string()})));
+        let signal = strategy.predict(&snapshot()).unwrap();
+        assert_eq!(signal.reasoning, "é".repeat(256));
+    }
+
+    #[test]
+    fn test_llm_prediction_from_answer_lines() {
+        let content =
+            "Analysis...\nACTION: BUY\nPRICE: $3,600.50\nCONFIDENCE: 80%\nREASONING: Uptrend";
+        let (strategy, http) = llm_strategy("deepseek", completion(json!({"content": content})));
+
+        let signal = strategy.predict(&snapshot()).unwrap();
+        let requests = http.requests.lock().unwrap();
+        assert_eq!(requests[0].url, "http://proxy/deepseek/chat/completions");
+        assert_eq!(signal.action, Action::Buy);
+        assert_eq!(signal.predicted_price_micro, 3_600_500_000);
+        assert_eq!(signal.confidence_bps, 8000);
+        assert_eq!(signal.reasoning, "Uptrend");
+    }
+
+    #[test]
+    fn test_llm_answer_is_strict() {
+        let unparseable = [
+            // Unknown field and no answer lines
+            r#"{"action": "BUY", "price": 3600, "confidence": 80, "reasoning": "", "extra": 1}"#,
+            // Out of range confidence
+            r#"{"action": "BUY", "price": 3600, "confidence": 150, "reasoning": ""}"#,
+            // Missing confidence line
+            "ACTION: BUY\nPRICE: 3600",
+            // Unknown action
+            "ACTION: MAYBE\nPRICE: 3600\nCONFIDENCE: 50",
+            "I cannot predict the market.",
+        ];
+        for content in unparseable {
+            let (strategy, _) = llm_strategy("deepseek", completion(json!({"content": content})));
+            assert!(
+                matches!(
+                    strategy.predict(&snapshot()),
+                    Err(StrategyError::UnparseableResponse { .. })
+                ),
+                "{content}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_llm_prediction_errors() {
+        let (strategy, _) = llm_strategy("qwen-vertex", http::Response::unauthorized());
+        assert!(matches!(
+            strategy.predict(&snapshot()),
+            Err(StrategyError::Api { provider: "Vertex AI", status: 401, .. })
+        ));
+
+        let (strategy, _) = llm_strategy("gemma", completion(json!({})));
+        assert_eq!(strategy.predict(&snapshot()), Err(StrategyError::EmptyResponse));
+    }
+}
@@ -0,0 +1,273 @@
+//! Prediction strategies selectable in the service's `executePrediction` mutation
+//!
+//! A strategy turns a `MarketSnapshot` into a `Signal`. To add one, implement
+//! `PredictionStrategy` in its own module and register it in
+//! `StrategyRegistry::with_defaults`.
+
+mod ensemble;
+mod llm;
+mod technical;
+
+use std::{collections::BTreeMap, rc::Rc, sync::Arc};
+
+use linera_sdk::{http, Service, ServiceRuntime};
+use serde::Serialize;
+use thiserror::Error;
+
+use bot_state::{BotParameters, Signal, LLM_STRATEGIES};
+
+use ensemble::EnsembleStrategy;
+use llm::{LlmEndpoint, LlmStrategy};
+use technical::TechnicalStrategy;
+
+/// Longest reasoning accepted by `Signal::validate`, in bytes
+const MAX_REASONING_BYTES: usize = 512;
+
+/// Market snapshot for prediction
+#[derive(Debug, Clone, Serialize)]
+pub struct MarketSnapshot {
+    /// Binance trading pair, e.g. "ETHUSDT"
+    pub symbol: String,
+    /// Unix timestamp in milliseconds
+    pub timestamp: u64,
+    /// How far ahead the prediction targets, in milliseconds
+    pub horizon_ms: u64,
+    /// Binance kline interval of `price_history`, e.g. "1h"
+    pub interval: String,
+    pub current_price: f64,
+    pub change_24h: f64,
+    pub volume_24h: f64,
+    /// Candles from oldest to newest
+    pub price_history: Vec<PricePoint>,
+}
+
+impl MarketSnapshot {
+    /// Unix timestamp in milliseconds the prediction targets
+    pub fn target_timestamp(&self) -> Result<u64, StrategyError> {
+        self.timestamp
+            .checked_add(self.horizon_ms)
+            .ok_or(StrategyError::HorizonOverflow(self.horizon_ms))
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PricePoint {
+    pub timestamp: u64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+/// Reasons a strategy produces no signal
+#[derive(Debug, Error, PartialEq)]
+pub enum StrategyError {
+    #[error("API key required for {0}")]
+    MissingApiKey(&'static str),
+
+    #[error("Failed to serialize request: {0}")]
+    InvalidRequest(String),
+
+    #[error("{provider} API error: {status} - {body}")]
+    Api {
+        provider: &'static str,
+        status: u16,
+        body: String,
+    },
+
+    #[error("Failed to parse response: {0}")]
+    InvalidResponse(String),
+
+    #[error("No content in response")]
+    EmptyResponse,
+
+    #[error("Unparseable LLM answer: not the JSON object ({json}) nor answer lines ({lines})")]
+    UnparseableResponse { json: String, lines: String },
+
+    #[error("Not enough price history: {needed} candles needed, got {available}")]
+    InsufficientHistory { needed: usize, available: usize },
+
+    #[error("No sub-strategy produced a signal: {0}")]
+    NoSignals(String),
+
+    #[error("Prediction horizon of {0} ms overflows the target timestamp")]
+    HorizonOverflow(u64),
+}
+
+/// Produces a prediction signal from a market snapshot
+pub trait PredictionStrategy {
+    /// Name selecting the strategy in `executePrediction`
+    fn name(&self) -> &str;
+
+    /// Predict `snapshot.symbol` `snapshot.horizon_ms` after `snapshot.timestamp`
+    ///
+    /// The returned signal's id is assigned by the contract.
+    fn predict(&self, snapshot: &MarketSnapshot) -> Result<Signal, StrategyError>;
+}
+
+/// Sends HTTP requests for strategies (the service runtime, or a fake in tests)
+pub trait HttpClient {
+    fn send(&self, request: http::Request) -> http::Response;
+}
+
+impl<Application: Service> HttpClient for ServiceRuntime<Application> {
+    fn send(&self, request: http::Request) -> http::Response {
+        self.http_request(request)
+    }
+}
+
+/// Strategies available to `executePrediction`, by name
+#[derive(Default)]
+pub struct StrategyRegistry {
+    strategies: BTreeMap<String, Rc<dyn PredictionStrategy>>,
+}
+
+impl StrategyRegistry {
+    /// Registry with every built-in strategy
+    ///
+    /// `api_key` is forwarded to the LLM providers.
+    pub fn with_defaults(
+        parameters: &BotParameters,
+        http: Arc<dyn HttpClient>,
+        api_key: Option<String>,
+    ) -> Self {
+        let mut registry = Self::default();
+        for name in LLM_STRATEGIES {
+            let endpoint = LlmEndpoint::for_strategy(name, parameters)
+                .expect("Every LLM strategy has an endpoint");
+            registry.register(LlmStrategy::new(
+                name,
+                endpoint,
+                &parameters.proxy_url,
+                http.clone(),
+                api_key.clone(),
+            ));
+        }
+        registry.register(TechnicalStrategy::new(parameters.technical.clone()));
+
+        let members = parameters
+            .ensemble
+            .strategies
+            .iter()
+            .map(|name| (name.clone(), registry.strategies.get(name).cloned()))
+            .collect();
+        registry.register(EnsembleStrategy::new(members));
+        registry
+    }
+
+    /// Add `strategy`, replacing any strategy registered under the same name
+    pub fn register(&mut self, strategy: impl PredictionStrategy + 'static) {
+        self.strategies.insert(strategy.name().to_string(), Rc::new(strategy));
+    }
+
+    /// Look up a strategy by name
+    pub fn get(&self, name: &str) -> Option<&dyn PredictionStrategy> {
+        self.strategies.get(name).map(|strategy| strategy.as_ref())
+    }
+}
+
+/// Cut `text` to at most `max_bytes` bytes on a character boundary
+fn truncate(mut text: String, max_bytes: usize) -> String {
+    if text.len() > max_bytes {
+        let mut end = max_bytes;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text.truncate(end);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use linera_sdk::http;
+
+    use bot_state::{Action, BotParameters, Signal};
+
+    use super::{
+        truncate, HttpClient, MarketSnapshot, PredictionStrategy, StrategyError, StrategyRegistry,
+    };
+
+    struct NoHttp;
+
+    impl HttpClient for NoHttp {
+        fn send(&self, _request: http::Request) -> http::Response {
+            panic!("No HTTP request expected");
+        }
+    }
+
+    struct AlwaysHold;
+
+    impl PredictionStrategy for AlwaysHold {
+        fn name(&self) -> &str {
+            "always-hold"
+        }
+
+        fn predict(&self, snapshot: &MarketSnapshot) -> Result<Signal, StrategyError> {
+            let price_micro = (snapshot.current_price * 1_000_000.0) as u64;
+            Ok(Signal {
+                id: 0,
+                timestamp: snapshot.timestamp,
+                target_timestamp: snapshot.target_timestamp()?,
+                asset: snapshot.symbol.clone(),
+                action: Action::Hold,
+                reference_price_micro: price_micro,
+                predicted_price_micro: price_micro,
+                confidence_bps: 5000,
+                reasoning: "Always hold".to_string(),
+                actual_price_micro: None,
+                commitment: None,
+            })
+        }
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry =
+            StrategyRegistry::with_defaults(&BotParameters::default(), Arc::new(NoHttp), None);
+        let names = ["gemma", "deepseek", "qwen-vertex", "gpt-oss-vertex", "technical", "ensemble"];
+        for name in names {
+            assert_eq!(registry.get(name).unwrap().name(), name);
+        }
+        assert!(registry.get("always-hold").is_none());
+
+        registry.register(AlwaysHold);
+        let snapshot = MarketSnapshot {
+            symbol: "ETHUSDT".to_string(),
+            timestamp: 1000,
+            horizon_ms: 3_600_000,
+            interval: "1h".to_string(),
+            current_price: 3500.0,
+            change_24h: 0.0,
+            volume_24h: 0.0,
+            price_history: Vec::new(),
+        };
+        let signal = registry.get("always-hold").unwrap().predict(&snapshot).unwrap();
+        assert_eq!(signal.action, Action::Hold);
+        assert_eq!(signal.predicted_price_micro, 3_500_000_000);
+
+        // Without an API key, LLM strategies fail before sending any request
+        assert_eq!(
+            registry.get("gemma").unwrap().predict(&snapshot),
+            Err(StrategyError::MissingApiKey("inference.net"))
+        );
+
+        let overflowing = MarketSnapshot {
+            horizon_ms: u64::MAX,
+            ..snapshot
+        };
+        assert_eq!(
+            registry.get("always-hold").unwrap().predict(&overflowing),
+            Err(StrategyError::HorizonOverflow(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("héllo".to_string(), 2), "h");
+        assert_eq!(truncate("hello".to_string(), 10), "hello");
+    }
+}
@@ -0,0 +1,273 @@
+use bot_state::{Action, Signal, TechnicalParameters, TECHNICAL_STRATEGY};
+
+use super::{MarketSnapshot, PredictionStrategy, StrategyError};
+
+/// Deterministic strategy voting with SMA, MACD and RSI indicators over the closes
+///
+/// Needs no external calls, so the same snapshot always gives the same signal.
+pub struct TechnicalStrategy {
+    parameters: TechnicalParameters,
+}
+
+impl TechnicalStrategy {
+    pub fn new(parameters: TechnicalParameters) -> Self {
+        TechnicalStrategy { parameters }
+    }
+}
+
+impl PredictionStrategy for TechnicalStrategy {
+    fn name(&self) -> &str {
+        TECHNICAL_STRATEGY
+    }
+
+    fn predict(&self, snapshot: &MarketSnapshot) -> Result<Signal, StrategyError> {
+        let parameters = &self.parameters;
+        let needed = parameters.required_history();
+        if snapshot.price_history.len() < needed {
+            return Err(StrategyError::InsufficientHistory {
+                needed,
+                available: snapshot.price_history.len(),
+            });
+        }
+        let closes: Vec<f64> = snapshot.price_history.iter().map(|candle| candle.close).collect();
+
+        let sma_fast = sma(&closes, parameters.sma_fast_period as usize);
+        let sma_slow = sma(&closes, parameters.sma_slow_period as usize);
+        let ema_fast = ema(&closes, parameters.macd_fast_period as usize);
+        let ema_slow = ema(&closes, parameters.macd_slow_period as usize);
+        let (macd_line, macd_signal) = macd(
+            &closes,
+            parameters.macd_fast_period as usize,
+            parameters.macd_slow_period as usize,
+            parameters.macd_signal_period as usize,
+        );
+        let rsi = rsi(&closes, parameters.rsi_period as usize);
+
+        let sma_vote = vote(sma_fast - sma_slow);
+        let macd_vote = vote(macd_line - macd_signal);
+        let rsi_vote = if rsi >= f64::from(parameters.rsi_overbought) {
+            -1
+        } else if rsi <= f64::from(parameters.rsi_oversold) {
+            1
+        } else {
+            0
+        };
+        let score = sma_vote + macd_vote + rsi_vote;
+
+        let min_votes = parameters.min_votes as i32;
+        let action = if score >= min_votes {
+            Action::Buy
+        } else if score <= -min_votes {
+            Action::Sell
+        } else {
+            Action::Hold
+        };
+
+        // Expect a move of the typical recent candle in the voted direction
+        let volatility = mean_abs_return(&closes, parameters.sma_fast_period as usize);
+        let predicted_price = match action {
+            Action::Buy => snapshot.current_price * (1.0 + volatility),
+            Action::Sell => snapshot.current_price * (1.0 - volatility),
+            Action::Hold => snapshot.current_price,
+        };
+        let confidence_bps = match action {
+            Action::Hold => 5000,
+            _ => 5000 + 1500 * u64::from(score.unsigned_abs()),
+        };
+
+        let reasoning = format!(
+            "SMA{} {:.2} vs SMA{} {:.2} ({}); EMA{} {:.2}, EMA{} {:.2}, \
+             MACD {:.3} vs signal {:.3} ({}); RSI{} {:.1} ({}); net votes {:+}",
+            parameters.sma_fast_period, sma_fast, parameters.sma_slow_period, sma_slow,
+            describe_vote(sma_vote),
+            parameters.macd_fast_period, ema_fast, parameters.macd_slow_period, ema_slow,
+            macd_line, macd_signal, describe_vote(macd_vote),
+            parameters.rsi_period, rsi, describe_vote(rsi_vote),
+            score
+        );
+
+        Ok(Signal {
+            id: 0, // Assigned by the contract
+            timestamp: snapshot.timestamp,
+            target_timestamp: snapshot.target_timestamp()?,
+            asset: snapshot.symbol.clone(),
+            action,
+            reference_price_micro: (snapshot.current_price * 1_000_000.0) as u64,
+            predicted_price_micro: (predicted_price * 1_000_000.0) as u64,
+            confidence_bps,
+            reasoning,
+            actual_price_micro: None,
+            commitment: None,
+        })
+    }
+}
+
+/// Bullish (1), bearish (-1) or neutral (0) vote from the sign of `spread`
+fn vote(spread: f64) -> i32 {
+    if spread > 0.0 {
+        1
+    } else if spread < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
+
+fn describe_vote(vote: i32) -> &'static str {
+    match vote {
+        1 => "bullish",
+        -1 => "bearish",
+        _ => "neutral",
+    }
+}
+
+/// Simple moving average of the last `period` values
+fn sma(values: &[f64], period: usize) -> f64 {
+    values[values.len() - period..].iter().sum::<f64>() / period as f64
+}
+
+/// Exponential moving average after each value from index `period - 1` on, seeded with
+/// the SMA of the first `period` values
+fn ema_series(values: &[f64], period: usize) -> Vec<f64> {
+    let alpha = 2.0 / (period as f64 + 1.0);
+    let mut ema = values[..period].iter().sum::<f64>() / period as f64;
+    let mut series = vec![ema];
+    for value in &values[period..] {
+        ema = alpha * value + (1.0 - alpha) * ema;
+        series.push(ema);
+    }
+    series
+}
+
+/// Latest exponential moving average
+fn ema(values: &[f64], period: usize) -> f64 {
+    *ema_series(values, period).last().expect("EMA series is never empty")
+}
+
+/// Latest MACD line (fast EMA minus slow EMA) and its signal line
+fn macd(values: &[f64], fast: usize, slow: usize, signal: usize) -> (f64, f64) {
+    let fast_series = ema_series(values, fast);
+    let slow_series = ema_series(values, slow);
+    // Both series end at the latest value, the fast one starts `slow - fast` values earlier
+    let line: Vec<f64> = slow_series
+        .iter()
+        .zip(&fast_series[slow - fast..])
+        .map(|(slow_ema, fast_ema)| fast_ema - slow_ema)
+        .collect();
+    (*line.last().expect("MACD line is never empty"), ema(&line, signal))
+}
+
+/// Relative strength index with Wilder's smoothing (0-100)
+fn rsi(values: &[f64], period: usize) -> f64 {
+    let changes: Vec<f64> = values.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    let (first, rest) = changes.split_at(period);
+    let period = period as f64;
+    let mut gain = first.iter().map(|change| change.max(0.0)).sum::<f64>() / period;
+    let mut loss = first.iter().map(|change| (-change).max(0.0)).sum::<f64>() / period;
+    for change in rest {
+        gain = (gain * (period - 1.0) + change.max(0.0)) / period;
+        loss = (loss * (period - 1.0) + (-change).max(0.0)) / period;
+    }
+    if loss == 0.0 {
+        return 100.0;
+    }
+    100.0 - 100.0 / (1.0 + gain / loss)
+}
+
+/// Mean absolute relative change between consecutive values over the last `period` changes
+fn mean_abs_return(values: &[f64], period: usize) -> f64 {
+    let recent = &values[values.len() - period - 1..];
+    recent
+        .windows(2)
+        .map(|pair| ((pair[1] - pair[0]) / pair[0]).abs())
+        .sum::<f64>()
+        / period as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use bot_state::{Action, TechnicalParameters};
+
+    use super::{ema, macd, rsi, sma, TechnicalStrategy};
+    use crate::strategy::{MarketSnapshot, PredictionStrategy, PricePoint, StrategyError};
+
+    fn snapshot(closes: &[f64]) -> MarketSnapshot {
+        MarketSnapshot {
+            symbol: "ETHUSDT".to_string(),
+            timestamp: 1000,
+            horizon_ms: 3_600_000,
+            interval: "1h".to_string(),
+            current_price: *closes.last().unwrap(),
+            change_24h: 0.0,
+            volume_24h: 0.0,
+            price_history: closes
+                .iter()
+                .enumerate()
+                .map(|(i, &close)| PricePoint {
+                    timestamp: i as u64 * 3_600_000,
+                    open: close,
+                    high: close,
+                    low: close,
+                    close,
+                    volume: 0.0,
+                })
+                .collect(),
+        }
+    }
+
+    /// Accelerating trend with a pullback every other candle
+    fn trend(start: f64, acceleration: f64) -> Vec<f64> {
+        (0..200)
+            .map(|i: u32| {
+                let pullback = if i % 2 == 0 { 4.0 } else { 0.0 };
+                start + acceleration * f64::from(i * i) + pullback
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_indicators() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(sma(&values, 3), 4.0);
+        assert_eq!(ema(&[2.0; 10], 3), 2.0);
+        let (line, signal) = macd(&[2.0; 40], 12, 26, 9);
+        assert!(line.abs() < 1e-9 && signal.abs() < 1e-9);
+        assert_eq!(rsi(&values, 3), 100.0);
+        assert_eq!(rsi(&[5.0, 4.0, 3.0, 2.0, 1.0], 3), 0.0);
+        assert_eq!(rsi(&[1.0, 2.0, 1.0], 2), 50.0);
+    }
+
+    #[test]
+    fn test_technical_signals() {
+        let strategy = TechnicalStrategy::new(TechnicalParameters::default());
+
+        let signal = strategy.predict(&snapshot(&trend(100.0, 0.002))).unwrap();
+        assert_eq!(signal.action, Action::Buy);
+        assert!(signal.predicted_price_micro > signal.reference_price_micro);
+        assert_eq!(signal.confidence_bps, 8000);
+        assert!(signal.reasoning.contains("SMA20"));
+
+        let signal = strategy.predict(&snapshot(&trend(300.0, -0.002))).unwrap();
+        assert_eq!(signal.action, Action::Sell);
+        assert!(signal.predicted_price_micro < signal.reference_price_micro);
+
+        // A steady rally is overbought, which outweighs one of the bullish votes
+        let closes: Vec<f64> = (0..200).map(|i| 100.0 + f64::from(i)).collect();
+        let signal = strategy.predict(&snapshot(&closes)).unwrap();
+        assert_eq!(signal.action, Action::Hold);
+        assert!(signal.reasoning.contains("(bearish)"));
+        assert_eq!(signal, strategy.predict(&snapshot(&closes)).unwrap());
+    }
+
+    #[test]
+    fn test_technical_needs_history() {
+        let strategy = TechnicalStrategy::new(TechnicalParameters::default());
+        assert_eq!(
+            strategy.predict(&snapshot(&[100.0; 49])),
+            Err(StrategyError::InsufficientHistory {
+                needed: 50,
+                available: 49
+            })
+        );
+    }
+}
@@ -1,42 +1,72 @@
-use bot_state::{AccuracyMetrics, Action, Signal};
+use bot_state::{
+    AccuracyMetrics, Action, BotParameters, BotStateError, EnsembleParameters, MetricsWindow,
+    Signal, SlashingPolicy, TechnicalParameters, DEFAULT_HOLD_BAND_BPS, RESOLUTION_GRACE_MS,
+};
+use linera_sdk::linera_base_types::{Account, AccountOwner, Amount, ChainId, CryptoHash};
 
 #[test]
 fn test_signal_validation_success() {
     let signal = Signal {
+        id: 0,
         timestamp: 1000000,
+        target_timestamp: 4600000,
+        asset: "ETHUSDT".to_string(),
         action: Action::Buy,
-        predicted_price: 2500.0,
-        confidence: 0.75,
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 2_500_000_000,
+        confidence_bps: 7500,
         reasoning: "Strong momentum indicators".to_string(),
-        actual_price: None,
+        actual_price_micro: None,
+        commitment: None,
     };
 
     assert!(signal.validate().is_ok());
 }
 
+#[test]
+fn test_signal_validation_invalid_asset() {
+    for asset in ["", "ethusdt", "ETH/USDT", "ETHUSDTETHUSDTETHUSDT"] {
+        assert_eq!(
+            Signal::validate_asset(asset),
+            Err(BotStateError::InvalidAsset(asset.to_string()))
+        );
+    }
+    assert!(Signal::validate_asset("BTCUSDT").is_ok());
+}
+
 #[test]
 fn test_signal_validation_invalid_confidence() {
     let signal = Signal {
+        id: 0,
         timestamp: 1000000,
+        target_timestamp: 4600000,
+        asset: "ETHUSDT".to_string(),
         action: Action::Buy,
-        predicted_price: 2500.0,
-        confidence: 1.5, // Invalid: > 1.0
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 2_500_000_000,
+        confidence_bps: 15000, // Invalid: > 10000
         reasoning: "Test".to_string(),
-        actual_price: None,
+        actual_price_micro: None,
+        commitment: None,
     };
 
-    assert!(signal.validate().is_err());
+    assert_eq!(signal.validate(), Err(BotStateError::ConfidenceOutOfRange(15000)));
 }
 
 #[test]
 fn test_signal_validation_negative_price() {
     let signal = Signal {
+        id: 0,
         timestamp: 1000000,
+        target_timestamp: 4600000,
+        asset: "ETHUSDT".to_string(),
         action: Action::Buy,
-        predicted_price: -100.0, // Invalid
-        confidence: 0.75,
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 0, // Invalid: must be positive
+        confidence_bps: 7500,
         reasoning: "Test".to_string(),
-        actual_price: None,
+        actual_price_micro: None,
+        commitment: None,
     };
 
     assert!(signal.validate().is_err());
@@ -46,75 +76,159 @@ fn test_signal_validation_negative_price() {
 fn test_signal_validation_reasoning_too_long() {
     let long_reasoning = "x".repeat(513); // Invalid: > 512 chars
     let signal = Signal {
+        id: 0,
         timestamp: 1000000,
+        target_timestamp: 4600000,
+        asset: "ETHUSDT".to_string(),
         action: Action::Buy,
-        predicted_price: 2500.0,
-        confidence: 0.75,
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 2_500_000_000,
+        confidence_bps: 7500,
         reasoning: long_reasoning,
-        actual_price: None,
+        actual_price_micro: None,
+        commitment: None,
+    };
+
+    assert!(signal.validate().is_err());
+}
+
+#[test]
+fn test_signal_validation_target_before_timestamp() {
+    let signal = Signal {
+        id: 0,
+        timestamp: 1000000,
+        target_timestamp: 1000000, // Invalid: must be after timestamp
+        asset: "ETHUSDT".to_string(),
+        action: Action::Buy,
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 2_500_000_000,
+        confidence_bps: 7500,
+        reasoning: "Test".to_string(),
+        actual_price_micro: None,
+        commitment: None,
     };
 
     assert!(signal.validate().is_err());
 }
 
+#[test]
+fn test_signal_resolvable_within_grace_window() {
+    let signal = Signal {
+        id: 0,
+        timestamp: 1000000,
+        target_timestamp: 4600000,
+        asset: "ETHUSDT".to_string(),
+        action: Action::Buy,
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 2_500_000_000,
+        confidence_bps: 7500,
+        reasoning: "Test".to_string(),
+        actual_price_micro: None,
+        commitment: None,
+    };
+
+    assert!(!signal.is_resolvable_at(4600000 - RESOLUTION_GRACE_MS - 1, RESOLUTION_GRACE_MS));
+    assert!(signal.is_resolvable_at(4600000 - RESOLUTION_GRACE_MS, RESOLUTION_GRACE_MS));
+    assert!(signal.is_resolvable_at(4600000 + RESOLUTION_GRACE_MS, RESOLUTION_GRACE_MS));
+    assert!(!signal.is_resolvable_at(4600000 + RESOLUTION_GRACE_MS + 1, RESOLUTION_GRACE_MS));
+    assert!(signal.is_overdue_at(4600001));
+}
+
 #[test]
 fn test_directional_accuracy_buy_correct() {
     let signal = Signal {
+        id: 0,
         timestamp: 1000000,
+        target_timestamp: 4600000,
+        asset: "ETHUSDT".to_string(),
         action: Action::Buy,
-        predicted_price: 2600.0,
-        confidence: 0.75,
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 2_600_000_000,
+        confidence_bps: 7500,
         reasoning: "Bullish".to_string(),
-        actual_price: Some(2550.0),
+        actual_price_micro: Some(2_550_000_000),
+        commitment: None,
     };
 
-    let previous_price = 2500.0;
-    assert_eq!(signal.is_directionally_correct(previous_price), Some(true));
+    assert_eq!(signal.is_directionally_correct(DEFAULT_HOLD_BAND_BPS), Some(true));
 }
 
 #[test]
 fn test_directional_accuracy_buy_incorrect() {
     let signal = Signal {
+        id: 0,
         timestamp: 1000000,
+        target_timestamp: 4600000,
+        asset: "ETHUSDT".to_string(),
         action: Action::Buy,
-        predicted_price: 2600.0,
-        confidence: 0.75,
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 2_600_000_000,
+        confidence_bps: 7500,
         reasoning: "Bullish".to_string(),
-        actual_price: Some(2450.0), // Price went down
+        actual_price_micro: Some(2_450_000_000), // Price went down
+        commitment: None,
     };
 
-    let previous_price = 2500.0;
-    assert_eq!(signal.is_directionally_correct(previous_price), Some(false));
+    assert_eq!(signal.is_directionally_correct(DEFAULT_HOLD_BAND_BPS), Some(false));
 }
 
 #[test]
 fn test_directional_accuracy_sell_correct() {
     let signal = Signal {
+        id: 0,
         timestamp: 1000000,
+        target_timestamp: 4600000,
+        asset: "ETHUSDT".to_string(),
         action: Action::Sell,
-        predicted_price: 2400.0,
-        confidence: 0.75,
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 2_400_000_000,
+        confidence_bps: 7500,
         reasoning: "Bearish".to_string(),
-        actual_price: Some(2450.0), // Price went down
+        actual_price_micro: Some(2_450_000_000), // Price went down
+        commitment: None,
     };
 
-    let previous_price = 2500.0;
-    assert_eq!(signal.is_directionally_correct(previous_price), Some(true));
+    assert_eq!(signal.is_directionally_correct(DEFAULT_HOLD_BAND_BPS), Some(true));
 }
 
 #[test]
 fn test_directional_accuracy_hold_correct() {
     let signal = Signal {
+        id: 0,
         timestamp: 1000000,
+        target_timestamp: 4600000,
+        asset: "ETHUSDT".to_string(),
         action: Action::Hold,
-        predicted_price: 2500.0,
-        confidence: 0.60,
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 2_500_000_000,
+        confidence_bps: 6000,
         reasoning: "Consolidation".to_string(),
-        actual_price: Some(2510.0), // Within 2% threshold
+        actual_price_micro: Some(2_510_000_000), // Within 2% threshold
+        commitment: None,
+    };
+
+    assert_eq!(signal.is_directionally_correct(DEFAULT_HOLD_BAND_BPS), Some(true));
+    // A 0.4% move is outside a 0.2% band
+    assert_eq!(signal.is_directionally_correct(20), Some(false));
+}
+
+#[test]
+fn test_directional_accuracy_uses_reference_price() {
+    let signal = Signal {
+        id: 0,
+        timestamp: 1000000,
+        target_timestamp: 4600000,
+        asset: "ETHUSDT".to_string(),
+        action: Action::Buy,
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 2_400_000_000, // Forecast below the outcome
+        confidence_bps: 7500,
+        reasoning: "Bullish".to_string(),
+        actual_price_micro: Some(2_450_000_000), // Market fell from the reference price
+        commitment: None,
     };
 
-    let previous_price = 2500.0;
-    assert_eq!(signal.is_directionally_correct(previous_price), Some(true));
+    assert_eq!(signal.is_directionally_correct(DEFAULT_HOLD_BAND_BPS), Some(false));
 }
 
 #[test]
@@ -122,54 +236,354 @@ fn test_accuracy_metrics_update() {
     let mut metrics = AccuracyMetrics::default();
 
     let signal = Signal {
+        id: 0,
         timestamp: 1000000,
+        target_timestamp: 4600000,
+        asset: "ETHUSDT".to_string(),
         action: Action::Buy,
-        predicted_price: 2600.0,
-        confidence: 0.75,
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 2_600_000_000,
+        confidence_bps: 7500,
         reasoning: "Test".to_string(),
-        actual_price: Some(2550.0), // Correct direction
+        actual_price_micro: Some(2_550_000_000), // Correct direction
+        commitment: None,
     };
 
-    let previous_price = 2500.0;
     let current_time = 2000000;
 
-    metrics.update(&signal, previous_price, current_time);
+    metrics.update(&signal, current_time, DEFAULT_HOLD_BAND_BPS);
 
     assert_eq!(metrics.total_predictions, 1);
     assert_eq!(metrics.correct_predictions, 1);
-    assert_eq!(metrics.directional_accuracy, 100.0);
-    assert!(metrics.rmse > 0.0); // Should have some error
+    assert_eq!(metrics.directional_accuracy_bps, 10_000);
+    assert!(metrics.rmse_micro > 0); // Should have some error
     assert_eq!(metrics.last_updated, current_time);
 }
 
 #[test]
 fn test_accuracy_metrics_multiple_updates() {
     let mut metrics = AccuracyMetrics::default();
-    let previous_price = 2500.0;
 
     // First signal: correct
     let signal1 = Signal {
+        id: 0,
         timestamp: 1000000,
+        target_timestamp: 4600000,
+        asset: "ETHUSDT".to_string(),
         action: Action::Buy,
-        predicted_price: 2600.0,
-        confidence: 0.75,
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 2_600_000_000,
+        confidence_bps: 7500,
         reasoning: "Test".to_string(),
-        actual_price: Some(2550.0),
+        actual_price_micro: Some(2_550_000_000),
+        commitment: None,
     };
-    metrics.update(&signal1, previous_price, 1000000);
+    metrics.update(&signal1, 1000000, DEFAULT_HOLD_BAND_BPS);
 
     // Second signal: incorrect
     let signal2 = Signal {
+        id: 0,
         timestamp: 2000000,
+        target_timestamp: 5600000,
+        asset: "ETHUSDT".to_string(),
         action: Action::Sell,
-        predicted_price: 2400.0,
-        confidence: 0.70,
+        reference_price_micro: 2_550_000_000,
+        predicted_price_micro: 2_400_000_000,
+        confidence_bps: 7000,
         reasoning: "Test".to_string(),
-        actual_price: Some(2600.0), // Went up instead of down
+        actual_price_micro: Some(2_600_000_000), // Went up instead of down
+        commitment: None,
     };
-    metrics.update(&signal2, 2550.0, 2000000);
+    metrics.update(&signal2, 2000000, DEFAULT_HOLD_BAND_BPS);
 
     assert_eq!(metrics.total_predictions, 2);
     assert_eq!(metrics.correct_predictions, 1);
-    assert_eq!(metrics.directional_accuracy, 50.0);
+    assert_eq!(metrics.directional_accuracy_bps, 5_000);
+}
+
+#[test]
+fn test_accuracy_metrics_rolling_window_expires() {
+    let mut metrics = AccuracyMetrics::default();
+    let hour = 3_600_000;
+
+    let correct = Signal {
+        id: 0,
+        timestamp: 1000000,
+        target_timestamp: 4600000,
+        asset: "ETHUSDT".to_string(),
+        action: Action::Buy,
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 2_600_000_000,
+        confidence_bps: 7500,
+        reasoning: "Test".to_string(),
+        actual_price_micro: Some(2_550_000_000),
+        commitment: None,
+    };
+    metrics.update(&correct, 0, DEFAULT_HOLD_BAND_BPS);
+
+    let incorrect = Signal {
+        action: Action::Sell,
+        ..correct.clone()
+    };
+    metrics.update(&incorrect, 12 * hour, DEFAULT_HOLD_BAND_BPS);
+
+    assert_eq!(metrics.total_predictions, 2);
+    assert_eq!(metrics.directional_accuracy_bps, 5_000);
+
+    // 24 hours after the first resolution, only the second one remains
+    metrics.advance_to(24 * hour);
+    assert_eq!(metrics.total_predictions, 1);
+    assert_eq!(metrics.correct_predictions, 0);
+    assert_eq!(metrics.directional_accuracy_bps, 0);
+
+    // After a long gap the window is empty
+    metrics.advance_to(100 * hour);
+    assert_eq!(metrics.total_predictions, 0);
+}
+
+#[test]
+fn test_accuracy_metrics_error_statistics() {
+    let mut metrics = AccuracyMetrics::default();
+
+    // Overshoots by 300 micro-USD
+    let over = Signal {
+        id: 0,
+        timestamp: 1000000,
+        target_timestamp: 4600000,
+        asset: "ETHUSDT".to_string(),
+        action: Action::Buy,
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 2_600_000_300,
+        confidence_bps: 7500,
+        reasoning: "Test".to_string(),
+        actual_price_micro: Some(2_600_000_000),
+        commitment: None,
+    };
+    metrics.update(&over, 1000000, DEFAULT_HOLD_BAND_BPS);
+
+    // Undershoots by 400 micro-USD
+    let under = Signal {
+        predicted_price_micro: 2_599_999_600,
+        ..over.clone()
+    };
+    metrics.update(&under, 2000000, DEFAULT_HOLD_BAND_BPS);
+
+    // sqrt((300^2 + 400^2) / 2) = sqrt(125000) = 353.55
+    assert_eq!(metrics.rmse_micro, 353);
+    assert_eq!(metrics.mae_micro, 350);
+    assert_eq!(metrics.bias_micro, -50);
+    assert_eq!(metrics.squared_error_sum, 250_000);
+}
+
+#[test]
+fn test_accuracy_metrics_missed_reveal() {
+    let mut metrics = AccuracyMetrics::new(MetricsWindow::OneHour);
+
+    // Correct Buy, off by 300 micro-USD
+    let signal = Signal {
+        id: 0,
+        timestamp: 1000000,
+        target_timestamp: 4600000,
+        asset: "ETHUSDT".to_string(),
+        action: Action::Buy,
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 2_600_000_300,
+        confidence_bps: 7500,
+        reasoning: "Test".to_string(),
+        actual_price_micro: Some(2_600_000_000),
+        commitment: None,
+    };
+    metrics.update(&signal, 1000000, DEFAULT_HOLD_BAND_BPS);
+    metrics.record_miss(1500000);
+
+    // The miss halves the accuracy but leaves the error statistics alone
+    assert_eq!(metrics.total_predictions, 2);
+    assert_eq!(metrics.missed_predictions, 1);
+    assert_eq!(metrics.directional_accuracy_bps, 5000);
+    assert_eq!(metrics.rmse_micro, 300);
+    assert_eq!(metrics.mae_micro, 300);
+
+    // Misses expire with their bucket
+    metrics.advance_to(1500000 + 3_600_000);
+    assert_eq!(metrics.total_predictions, 0);
+    assert_eq!(metrics.missed_predictions, 0);
+}
+
+#[test]
+fn test_accuracy_metrics_windows_expire_independently() {
+    let mut one_hour = AccuracyMetrics::new(MetricsWindow::OneHour);
+    let mut seven_days = AccuracyMetrics::new(MetricsWindow::SevenDays);
+    let mut all_time = AccuracyMetrics::new(MetricsWindow::AllTime);
+
+    let signal = Signal {
+        id: 0,
+        timestamp: 1000000,
+        target_timestamp: 4600000,
+        asset: "ETHUSDT".to_string(),
+        action: Action::Buy,
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 2_600_000_000,
+        confidence_bps: 7500,
+        reasoning: "Test".to_string(),
+        actual_price_micro: Some(2_550_000_000),
+        commitment: None,
+    };
+    for metrics in [&mut one_hour, &mut seven_days, &mut all_time] {
+        metrics.update(&signal, 1000000, DEFAULT_HOLD_BAND_BPS);
+        // Two days later
+        metrics.advance_to(1000000 + 48 * 3_600_000);
+    }
+
+    assert_eq!(one_hour.total_predictions, 0);
+    assert_eq!(seven_days.total_predictions, 1);
+    assert_eq!(all_time.total_predictions, 1);
+
+    all_time.advance_to(u64::MAX);
+    assert_eq!(all_time.total_predictions, 1);
+}
+
+#[test]
+fn test_accuracy_metrics_summary_omits_buckets() {
+    let metrics = AccuracyMetrics::new(MetricsWindow::OneHour);
+    let summary = metrics.summary();
+
+    assert!(summary.buckets.is_empty());
+    assert_eq!(summary.window, MetricsWindow::OneHour);
+    assert_eq!(summary.total_predictions, metrics.total_predictions);
+}
+
+fn slashing_policy() -> SlashingPolicy {
+    SlashingPolicy {
+        beneficiary: Account {
+            chain_id: ChainId(CryptoHash::test_hash("beneficiary")),
+            owner: AccountOwner::from(CryptoHash::test_hash("beneficiary")),
+        },
+        slash_bps: 1000, // 10%
+        accuracy_window: MetricsWindow::SevenDays,
+        min_accuracy_bps: 5000,
+        min_predictions: 2,
+        max_signal_interval_ms: Some(3_600_000),
+    }
+}
+
+#[test]
+fn test_parameters_validation() {
+    assert!(BotParameters::default().validate().is_ok());
+
+    let invalid = [
+        BotParameters { proxy_url: "localhost:3002".to_string(), ..Default::default() },
+        BotParameters { symbols: vec![], ..Default::default() },
+        BotParameters { kline_interval: "90m".to_string(), ..Default::default() },
+        BotParameters { kline_limit: 1001, ..Default::default() },
+        BotParameters { model_id: String::new(), ..Default::default() },
+        BotParameters { hold_band_bps: 10_001, ..Default::default() },
+        BotParameters { resolution_grace_ms: 0, ..Default::default() },
+        BotParameters { kline_limit: 40, ..Default::default() },
+        technical(TechnicalParameters { sma_fast_period: 50, ..Default::default() }),
+        technical(TechnicalParameters { macd_slow_period: 12, ..Default::default() }),
+        technical(TechnicalParameters { rsi_period: 0, ..Default::default() }),
+        technical(TechnicalParameters { rsi_oversold: 70, ..Default::default() }),
+        technical(TechnicalParameters { min_votes: 4, ..Default::default() }),
+        ensemble(&[]),
+        ensemble(&["technical", "ensemble"]),
+        ensemble(&["technical", "gemma", "technical"]),
+        ensemble(&["technical", "gemini"]),
+    ];
+    for parameters in invalid {
+        assert!(matches!(parameters.validate(), Err(BotStateError::InvalidParameter(_))));
+    }
+
+    let parameters = BotParameters {
+        symbols: vec!["btcusdt".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(
+        parameters.validate(),
+        Err(BotStateError::InvalidAsset("btcusdt".to_string()))
+    );
+
+    // Missing fields fall back to their defaults
+    let parameters: BotParameters = serde_json::from_str(r#"{"symbols": ["BTCUSDT"]}"#).unwrap();
+    assert_eq!(parameters.default_symbol(), "BTCUSDT");
+    assert_eq!(parameters.kline_limit, BotParameters::default().kline_limit);
+
+    let parameters: BotParameters =
+        serde_json::from_str(r#"{"technical": {"rsi_period": 7}}"#).unwrap();
+    assert_eq!(parameters.technical.rsi_period, 7);
+    assert_eq!(parameters.technical.sma_slow_period, 50);
+    assert_eq!(parameters.technical.required_history(), 50);
+}
+
+fn technical(technical: TechnicalParameters) -> BotParameters {
+    BotParameters { technical, ..Default::default() }
+}
+
+fn ensemble(strategies: &[&str]) -> BotParameters {
+    let strategies = strategies.iter().map(|name| name.to_string()).collect();
+    BotParameters { ensemble: EnsembleParameters { strategies }, ..Default::default() }
+}
+
+#[test]
+fn test_slashing_policy_validation() {
+    assert!(slashing_policy().validate().is_ok());
+
+    let mut policy = slashing_policy();
+    policy.slash_bps = 10_001;
+    assert_eq!(policy.validate(), Err(BotStateError::SlashOutOfRange(10_001)));
+
+    let mut policy = slashing_policy();
+    policy.max_signal_interval_ms = Some(0);
+    assert_eq!(policy.validate(), Err(BotStateError::ZeroSignalInterval));
+}
+
+#[test]
+fn test_slash_amount_is_share_of_stake() {
+    let policy = slashing_policy();
+
+    assert_eq!(policy.slash_amount(Amount::from_tokens(50)), Amount::from_tokens(5));
+    assert_eq!(policy.slash_amount(Amount::ZERO), Amount::ZERO);
+}
+
+#[test]
+fn test_accuracy_violation_requires_min_predictions() {
+    let policy = slashing_policy();
+    let mut metrics = AccuracyMetrics::new(MetricsWindow::SevenDays);
+
+    let wrong_signal = Signal {
+        id: 0,
+        timestamp: 1000000,
+        target_timestamp: 4600000,
+        asset: "ETHUSDT".to_string(),
+        action: Action::Buy,
+        reference_price_micro: 2_500_000_000,
+        predicted_price_micro: 2_600_000_000,
+        confidence_bps: 7500,
+        reasoning: "Test".to_string(),
+        actual_price_micro: Some(2_400_000_000),
+        commitment: None,
+    };
+    metrics.update(&wrong_signal, 4600000, DEFAULT_HOLD_BAND_BPS);
+    assert!(!policy.is_accuracy_violated(&metrics));
+
+    metrics.update(&wrong_signal, 4600000, DEFAULT_HOLD_BAND_BPS);
+    assert_eq!(metrics.directional_accuracy_bps, 0);
+    assert!(policy.is_accuracy_violated(&metrics));
+}
+
+#[test]
+fn test_missed_deadline_is_slashed_once_per_interval() {
+    let policy = slashing_policy();
+    let hour = 3_600_000;
+
+    // Next signal is due one interval after the latest one
+    assert_eq!(policy.missed_deadline(1000, 0, 1000 + hour), None);
+    assert_eq!(policy.missed_deadline(1000, 0, 1001 + hour), Some(1000 + hour));
+
+    // Once a deadline was slashed, the next one is an interval later
+    assert_eq!(policy.missed_deadline(1000, 1000 + hour, 1001 + hour), None);
+    assert_eq!(policy.missed_deadline(1000, 1000 + hour, 1001 + 2 * hour), Some(1000 + 2 * hour));
+
+    let mut unscheduled = slashing_policy();
+    unscheduled.max_signal_interval_ms = None;
+    assert_eq!(unscheduled.missed_deadline(1000, 0, u64::MAX), None);
 }
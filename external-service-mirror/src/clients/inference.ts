@@ -5,17 +5,26 @@ import type { ChatCompletionRequest, ChatCompletionResponse } from '../types/ind
  * inference.net API HTTP client
  * Handles LLM inference requests via OpenAI-compatible API
  * Transparently forwards Authorization header from incoming requests
+ * Also serves other OpenAI-compatible providers (DeepSeek, Vertex AI) given their base URL
  */
 export class InferenceClient {
   private baseUrl: string;
+  private provider: string;
+  private completionsPath: string;
 
-  constructor(baseUrl = 'https://api.inference.net') {
+  constructor(
+    baseUrl = 'https://api.inference.net',
+    provider = 'inference.net',
+    completionsPath = '/v1/chat/completions'
+  ) {
     this.baseUrl = baseUrl;
+    this.provider = provider;
+    this.completionsPath = completionsPath;
   }
 
   /**
    * Call chat completions endpoint
-   * Endpoint: POST /v1/chat/completions
+   * Endpoint: POST /v1/chat/completions (or the configured completions path)
    * @param request - Chat completion request
    * @param authHeader - Authorization header (e.g., "Bearer sk-...")
    */
@@ -23,7 +32,7 @@ export class InferenceClient {
     request: ChatCompletionRequest,
     authHeader: string
   ): Promise<ChatCompletionResponse> {
-    const url = `${this.baseUrl}/v1/chat/completions`;
+    const url = `${this.baseUrl}${this.completionsPath}`;
 
     const response = await this.fetchWithRetry(url, {
       method: 'POST',
@@ -57,7 +66,7 @@ export class InferenceClient {
         if (!response.ok) {
           const errorBody = await response.text();
           throw new Error(
-            `inference.net API error: ${response.status} ${response.statusText} - ${errorBody}`
+            `${this.provider} API error: ${response.status} ${response.statusText} - ${errorBody}`
           );
         }
 
@@ -25,10 +25,14 @@ app.listen(PORT, () => {
   console.log(`    GET  http://localhost:${PORT}/binance/ticker?symbol=ETHUSDT`);
   console.log(`    GET  http://localhost:${PORT}/binance/klines?symbol=ETHUSDT&interval=1h&limit=200`);
   console.log(`    POST http://localhost:${PORT}/inference/chat/completions`);
+  console.log(`    POST http://localhost:${PORT}/deepseek/chat/completions`);
+  console.log(`    POST http://localhost:${PORT}/vertex/:location/chat/completions`);
   console.log('');
   console.log('  Proxies to:');
   console.log(`    Binance:       ${process.env.BINANCE_API_URL || 'https://api.binance.com'}`);
   console.log(`    inference.net: ${process.env.INFERENCE_API_URL || 'https://api.inference.net'}`);
+  console.log(`    DeepSeek:      ${process.env.DEEPSEEK_API_URL || 'https://api.deepseek.com'}`);
+  console.log(`    Vertex AI:     ${process.env.GCP_PROJECT_ID ? `project ${process.env.GCP_PROJECT_ID}` : 'not configured (set GCP_PROJECT_ID)'}`);
   console.log('');
   console.log('='.repeat(60));
   console.log('');
@@ -0,0 +1,66 @@
+import type { Request, Response } from 'express';
+import type { InferenceClient } from '../clients/inference.js';
+import type { ChatCompletionRequest, ErrorResponse } from '../types/index.js';
+
+/**
+ * Shared handler for OpenAI-compatible chat completion proxies
+ *
+ * Transparently forwards Authorization header from service.rs to the provider
+ * Falls back to `fallbackApiKey` if no header provided (for testing)
+ * Responds 400 with `ErrorResponse` when `resolveClient` returns one instead of a client
+ */
+export function chatCompletionsHandler(
+  provider: string,
+  fallbackApiKey: string | undefined,
+  resolveClient: (req: Request) => InferenceClient | ErrorResponse
+) {
+  return async (req: Request, res: Response) => {
+    try {
+      const request = req.body as ChatCompletionRequest;
+
+      // Get Authorization header from request (sent by service.rs)
+      // Or use fallback from env for direct testing
+      const authHeader = req.headers.authorization || (fallbackApiKey ? `Bearer ${fallbackApiKey}` : null);
+
+      if (!authHeader) {
+        const error: ErrorResponse = {
+          error: 'Missing Authorization header',
+          details: `Either pass Authorization header from service.rs or set the ${provider} key in .env for testing`,
+        };
+        return res.status(401).json(error);
+      }
+
+      // Validate request body
+      if (!request.model || !request.messages) {
+        const error: ErrorResponse = {
+          error: 'Invalid request body',
+          details: 'Request must include "model" and "messages" fields',
+        };
+        return res.status(400).json(error);
+      }
+
+      if (!Array.isArray(request.messages) || request.messages.length === 0) {
+        const error: ErrorResponse = {
+          error: 'Invalid messages array',
+          details: 'Messages must be a non-empty array',
+        };
+        return res.status(400).json(error);
+      }
+
+      const client = resolveClient(req);
+      if ('error' in client) {
+        return res.status(400).json(client);
+      }
+
+      const response = await client.chatCompletions(request, authHeader);
+      res.json(response);
+    } catch (error) {
+      console.error(`${provider} proxy error:`, error);
+      const errorResponse: ErrorResponse = {
+        error: `Failed to call ${provider} API`,
+        details: error instanceof Error ? error.message : String(error),
+      };
+      res.status(500).json(errorResponse);
+    }
+  };
+}
@@ -0,0 +1,25 @@
+import express, { Router } from 'express';
+import { InferenceClient } from '../clients/inference.js';
+import { chatCompletionsHandler } from './chat.js';
+
+const router: Router = express.Router();
+
+// DeepSeek exposes the same OpenAI-compatible API as inference.net
+const deepseekClient = new InferenceClient(
+  process.env.DEEPSEEK_API_URL || 'https://api.deepseek.com',
+  'DeepSeek'
+);
+
+// Fallback API key for direct testing (when service.rs doesn't send header)
+const fallbackApiKey = process.env.DEEPSEEK_API_KEY;
+
+/**
+ * POST /deepseek/chat/completions
+ * Proxies to: https://api.deepseek.com/v1/chat/completions
+ *
+ * Transparently forwards Authorization header from service.rs to DeepSeek
+ * Falls back to DEEPSEEK_API_KEY env var if no header provided (for testing)
+ */
+router.post('/chat/completions', chatCompletionsHandler('DeepSeek', fallbackApiKey, () => deepseekClient));
+
+export default router;
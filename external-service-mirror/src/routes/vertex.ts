@@ -0,0 +1,48 @@
+import express, { Router } from 'express';
+import { InferenceClient } from '../clients/inference.js';
+import { chatCompletionsHandler } from './chat.js';
+
+const router: Router = express.Router();
+
+// Fallback access token for direct testing (e.g., `gcloud auth print-access-token`)
+const fallbackAccessToken = process.env.GCP_ACCESS_TOKEN;
+
+/**
+ * POST /vertex/:location/chat/completions
+ * Proxies to Vertex AI's OpenAI-compatible MaaS endpoint in GCP_PROJECT_ID:
+ *   global:    https://aiplatform.googleapis.com/v1/projects/PROJECT_ID/locations/global/endpoints/openapi
+ *   us-south1: https://us-south1-aiplatform.googleapis.com/v1/projects/PROJECT_ID/locations/us-south1/endpoints/openapi
+ *
+ * Transparently forwards Authorization header (a GCP access token) from service.rs
+ * Falls back to GCP_ACCESS_TOKEN env var if no header provided (for testing)
+ */
+router.post(
+  '/:location/chat/completions',
+  chatCompletionsHandler('Vertex AI', fallbackAccessToken, (req) => {
+    const projectId = process.env.GCP_PROJECT_ID;
+    if (!projectId) {
+      return {
+        error: 'Vertex AI is not configured',
+        details: 'Set GCP_PROJECT_ID in .env',
+      };
+    }
+
+    const location = req.params.location;
+    if (!/^[a-z0-9-]+$/.test(location)) {
+      return {
+        error: 'Invalid Vertex AI location',
+        details: 'Location must be "global" or a region such as "us-south1"',
+      };
+    }
+
+    // Regional endpoints are prefixed with their location, the global one is not
+    const domain = location === 'global' ? 'aiplatform.googleapis.com' : `${location}-aiplatform.googleapis.com`;
+    return new InferenceClient(
+      `https://${domain}/v1/projects/${projectId}/locations/${location}/endpoints/openapi`,
+      'Vertex AI',
+      '/chat/completions'
+    );
+  })
+);
+
+export default router;
@@ -1,6 +1,8 @@
 import express, { Express } from 'express';
 import binanceRoutes from './routes/binance.js';
+import deepseekRoutes from './routes/deepseek.js';
 import inferenceRoutes from './routes/inference.js';
+import vertexRoutes from './routes/vertex.js';
 
 /**
  * Create and configure Express app
@@ -29,12 +31,14 @@ export function createServer(): Express {
   // Mount route handlers
   app.use('/binance', binanceRoutes);
   app.use('/inference', inferenceRoutes);
+  app.use('/deepseek', deepseekRoutes);
+  app.use('/vertex', vertexRoutes);
 
   // 404 handler
   app.use((_req, res) => {
     res.status(404).json({
       error: 'Not Found',
-      details: 'Available endpoints: /health, /binance/ticker, /binance/klines, /inference/chat/completions',
+      details: 'Available endpoints: /health, /binance/ticker, /binance/klines, /inference/chat/completions, /deepseek/chat/completions, /vertex/:location/chat/completions',
     });
   });
 
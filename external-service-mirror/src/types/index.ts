@@ -46,11 +46,15 @@ export interface ChatCompletionRequest {
   top_p?: number;
   frequency_penalty?: number;
   presence_penalty?: number;
+  /** Structured output request, e.g. { type: 'json_object' } or a strict JSON schema */
+  response_format?: Record<string, unknown>;
 }
 
 export interface ChatMessage {
   role: 'system' | 'user' | 'assistant';
   content: string;
+  /** Set instead of `content` by reasoning models such as GPT OSS on Vertex AI */
+  reasoning_content?: string;
 }
 
 /**
@@ -7,10 +7,12 @@
 
 import { useState, useEffect } from 'react';
 import { BotCard } from '@/components/BotCard';
-import { BotState } from '@/types';
+import { BotState, BotStateResponse } from '@/types';
 import { queryLinera } from '@/lib/graphql/client';
 import { GET_BOT_STATE } from '@/lib/graphql/queries';
 
+const BOT_ID = process.env.NEXT_PUBLIC_BOT_ID || '';
+
 export default function Home() {
   const [botState, setBotState] = useState<BotState | null>(null);
   const [isLoading, setIsLoading] = useState(true);
@@ -27,8 +29,10 @@ export default function Home() {
 
       setError(null);
 
-      const data = await queryLinera<BotState>(GET_BOT_STATE);
-      setBotState(data);
+      const data = await queryLinera<BotStateResponse>(GET_BOT_STATE, {
+        botId: BOT_ID,
+      });
+      setBotState({ botId: BOT_ID, ...data });
     } catch (err) {
       console.error('Error fetching bot state:', err);
       setError(
@@ -133,7 +137,8 @@ export default function Home() {
                 Make sure you have set up your <code className="bg-blue-100 px-2 py-1 rounded">.env.local</code> file:
               </p>
               <pre className="bg-blue-900 text-blue-100 p-3 rounded text-xs overflow-x-auto">
-{`NEXT_PUBLIC_LINERA_GRAPHQL_URL=http://localhost:8081/chains/<CHAIN_ID>/applications/<APP_ID>`}
+{`NEXT_PUBLIC_LINERA_GRAPHQL_URL=http://localhost:8081/chains/<CHAIN_ID>/applications/<APP_ID>
+NEXT_PUBLIC_BOT_ID=<BOT_ID>`}
               </pre>
               <p className="text-xs text-blue-700 mt-2">
                 See <code className="bg-blue-100 px-1 rounded">.env.local.example</code> for details.
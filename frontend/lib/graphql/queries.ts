@@ -9,23 +9,22 @@ import { gql } from 'graphql-request';
  * Query to get complete bot state including latest signal and accuracy metrics
  */
 export const GET_BOT_STATE = gql`
-  query GetBotState {
-    botId
-    latestSignal {
+  query GetBotState($botId: String!) {
+    latestSignal(botId: $botId) {
       action
       predictedPriceMicro
       confidenceBps
       timestamp
       reasoning
     }
-    accuracy24H {
+    accuracy24H(botId: $botId) {
       totalPredictions
       correctPredictions
       directionalAccuracyBps
       rmseMicro
       lastUpdated
     }
-    followerCount
+    followerCount(botId: $botId)
   }
 `;
 
@@ -33,8 +32,8 @@ export const GET_BOT_STATE = gql`
  * Query to get only the latest signal
  */
 export const GET_LATEST_SIGNAL = gql`
-  query GetLatestSignal {
-    latestSignal {
+  query GetLatestSignal($botId: String!) {
+    latestSignal(botId: $botId) {
       action
       predictedPriceMicro
       confidenceBps
@@ -48,8 +47,8 @@ export const GET_LATEST_SIGNAL = gql`
  * Query to get only accuracy metrics
  */
 export const GET_ACCURACY_METRICS = gql`
-  query GetAccuracyMetrics {
-    accuracy24H {
+  query GetAccuracyMetrics($botId: String!) {
+    accuracy24H(botId: $botId) {
       totalPredictions
       correctPredictions
       directionalAccuracyBps
@@ -63,7 +62,7 @@ export const GET_ACCURACY_METRICS = gql`
  * Query to get follower count
  */
 export const GET_FOLLOWER_COUNT = gql`
-  query GetFollowerCount {
-    followerCount
+  query GetFollowerCount($botId: String!) {
+    followerCount(botId: $botId)
   }
 `;
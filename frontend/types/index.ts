@@ -35,7 +35,6 @@ export interface BotState {
  * GraphQL response types
  */
 export interface BotStateResponse {
-  botId: string;
   latestSignal: Signal | null;
   accuracy24H: AccuracyMetrics;
   followerCount: number;
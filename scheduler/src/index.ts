@@ -1,23 +1,40 @@
+import { randomBytes } from 'crypto';
 import cron from 'node-cron';
-import { GraphQLClient, gql } from 'graphql-request';
+import { ClientError, GraphQLClient, gql } from 'graphql-request';
 
 // Configuration from environment variables
 const LINERA_GRAPHQL_URL = process.env.LINERA_GRAPHQL_URL;
+const BOT_ID = process.env.BOT_ID;
 const STRATEGY = process.env.STRATEGY || 'gemma';
 const CRON_SCHEDULE = process.env.CRON_SCHEDULE || '0 * * * *'; // Every hour
 const INFERENCE_API_KEY = process.env.INFERENCE_API_KEY;
+const HORIZON_MS = parseInt(process.env.HORIZON_MS || '3600000', 10); // 1 hour
+// Predictions must be revealed before their target timestamp
+const REVEAL_DELAY_MS = parseInt(process.env.REVEAL_DELAY_MS || String(HORIZON_MS / 2), 10);
 
 if (!LINERA_GRAPHQL_URL) {
   console.error('ERROR: LINERA_GRAPHQL_URL environment variable is required');
   process.exit(1);
 }
 
-// GraphQL mutation to trigger prediction
-const EXECUTE_PREDICTION_MUTATION = gql`
-  mutation ExecutePrediction($strategy: String!, $apiKey: String) {
-    executePrediction(strategy: $strategy, apiKey: $apiKey) {
+if (!BOT_ID) {
+  console.error('ERROR: BOT_ID environment variable is required');
+  process.exit(1);
+}
+
+if (!(REVEAL_DELAY_MS > 0 && REVEAL_DELAY_MS < HORIZON_MS)) {
+  console.error('ERROR: REVEAL_DELAY_MS must be positive and less than HORIZON_MS');
+  process.exit(1);
+}
+
+// GraphQL query to run the strategy without scheduling anything
+const PREDICTION_QUERY = gql`
+  query Prediction($strategy: String!, $apiKey: String, $horizonMs: String) {
+    prediction(strategy: $strategy, apiKey: $apiKey, horizonMs: $horizonMs) {
       timestamp
+      asset
       action
+      referencePriceMicro
       predictedPriceMicro
       confidenceBps
       reasoning
@@ -25,31 +42,190 @@ const EXECUTE_PREDICTION_MUTATION = gql`
   }
 `;
 
+// GraphQL query to hash a prediction with its salt
+const COMMITMENT_HASH_QUERY = gql`
+  query CommitmentHash(
+    $botId: String!
+    $asset: String!
+    $action: Action!
+    $referencePriceMicro: String!
+    $predictedPriceMicro: String!
+    $confidenceBps: Int!
+    $reasoning: String!
+    $salt: String!
+  ) {
+    commitmentHash(
+      botId: $botId
+      asset: $asset
+      action: $action
+      referencePriceMicro: $referencePriceMicro
+      predictedPriceMicro: $predictedPriceMicro
+      confidenceBps: $confidenceBps
+      reasoning: $reasoning
+      salt: $salt
+    )
+  }
+`;
+
+// GraphQL mutation to commit the hidden prediction
+const COMMIT_PREDICTION_MUTATION = gql`
+  mutation CommitPrediction($botId: String!, $asset: String!, $commitment: CryptoHash!, $horizonMs: String!) {
+    commitPrediction(botId: $botId, asset: $asset, commitment: $commitment, horizonMs: $horizonMs)
+  }
+`;
+
+// GraphQL query to find the id of the commitment to reveal
+const LATEST_COMMITMENT_QUERY = gql`
+  query LatestCommitment($botId: String!, $asset: String!) {
+    latestCommitment(botId: $botId, asset: $asset) {
+      id
+      hash
+    }
+  }
+`;
+
+// GraphQL mutation to reveal a committed prediction so it is scored
+const REVEAL_PREDICTION_MUTATION = gql`
+  mutation RevealPrediction(
+    $botId: String!
+    $commitmentId: String!
+    $action: Action!
+    $referencePriceMicro: String!
+    $predictedPriceMicro: String!
+    $confidenceBps: Int!
+    $salt: String!
+    $reasoning: String!
+  ) {
+    revealPrediction(
+      botId: $botId
+      commitmentId: $commitmentId
+      action: $action
+      referencePriceMicro: $referencePriceMicro
+      predictedPriceMicro: $predictedPriceMicro
+      confidenceBps: $confidenceBps
+      salt: $salt
+      reasoning: $reasoning
+    )
+  }
+`;
+
+interface Prediction {
+  timestamp: number;
+  asset: string;
+  action: string;
+  referencePriceMicro: number;
+  predictedPriceMicro: number;
+  confidenceBps: number;
+  reasoning: string;
+}
+
 // Create GraphQL client
 const graphQLClient = new GraphQLClient(LINERA_GRAPHQL_URL);
 
 /**
- * Trigger a prediction by calling the contract's executePrediction mutation
+ * Submit a mutation to the node service
+ *
+ * Linera mutations answer with the hash of the block that includes them instead of a
+ * GraphQL result, which graphql-request reports as an error without GraphQL errors.
+ */
+async function mutate(document: string, variables: Record<string, unknown>) {
+  try {
+    await graphQLClient.request(document, variables);
+  } catch (error) {
+    if (error instanceof ClientError && !error.response.errors?.length) {
+      return;
+    }
+    throw error;
+  }
+}
+
+/**
+ * Reveal a committed prediction so it counts towards the bot's accuracy
+ */
+async function revealPrediction(prediction: Prediction, salt: string, commitment: string) {
+  const timestamp = new Date().toISOString();
+  console.log(`[${timestamp}] Revealing prediction made at ${prediction.timestamp}...`);
+
+  try {
+    const { latestCommitment } = await graphQLClient.request<{
+      latestCommitment: { id: number; hash: string } | null;
+    }>(LATEST_COMMITMENT_QUERY, { botId: BOT_ID, asset: prediction.asset });
+    if (latestCommitment?.hash !== commitment) {
+      console.error(`[${timestamp}] ❌ Commitment ${commitment} not found, not revealing`);
+      return;
+    }
+
+    await mutate(REVEAL_PREDICTION_MUTATION, {
+      botId: BOT_ID,
+      commitmentId: String(latestCommitment.id),
+      action: prediction.action,
+      referencePriceMicro: String(prediction.referencePriceMicro),
+      predictedPriceMicro: String(prediction.predictedPriceMicro),
+      confidenceBps: prediction.confidenceBps,
+      salt,
+      reasoning: prediction.reasoning,
+    });
+
+    console.log(`[${timestamp}] ✅ Prediction revealed (commitment ${latestCommitment.id})`);
+  } catch (error) {
+    console.error(`[${timestamp}] ❌ Failed to reveal prediction:`);
+    console.error(error);
+  }
+}
+
+/**
+ * Run the strategy, commit the prediction with a fresh salt and schedule its reveal
+ *
+ * Only revealed commitments are scored, so predictions are never submitted in cleartext.
+ * Pending reveals are kept in memory and lost if the scheduler restarts.
  */
 async function triggerPrediction() {
   const timestamp = new Date().toISOString();
   console.log(`[${timestamp}] Triggering prediction...`);
+  console.log(`  Bot:      ${BOT_ID}`);
   console.log(`  Strategy: ${STRATEGY}`);
   console.log(`  Endpoint: ${LINERA_GRAPHQL_URL}`);
 
   try {
-    const result = await graphQLClient.request(EXECUTE_PREDICTION_MUTATION, {
-      strategy: STRATEGY,
-      apiKey: INFERENCE_API_KEY,
+    const { prediction } = await graphQLClient.request<{ prediction: Prediction }>(
+      PREDICTION_QUERY,
+      {
+        strategy: STRATEGY,
+        apiKey: INFERENCE_API_KEY,
+        horizonMs: String(HORIZON_MS),
+      }
+    );
+
+    const salt = randomBytes(32).toString('hex');
+    const { commitmentHash } = await graphQLClient.request<{ commitmentHash: string }>(
+      COMMITMENT_HASH_QUERY,
+      {
+        botId: BOT_ID,
+        asset: prediction.asset,
+        action: prediction.action,
+        referencePriceMicro: String(prediction.referencePriceMicro),
+        predictedPriceMicro: String(prediction.predictedPriceMicro),
+        confidenceBps: prediction.confidenceBps,
+        reasoning: prediction.reasoning,
+        salt,
+      }
+    );
+
+    await mutate(COMMIT_PREDICTION_MUTATION, {
+      botId: BOT_ID,
+      asset: prediction.asset,
+      commitment: commitmentHash,
+      horizonMs: String(HORIZON_MS),
     });
 
-    console.log(`[${timestamp}] ✅ Prediction triggered successfully`);
-    console.log(`  Result: ${JSON.stringify(result)}`);
+    console.log(`[${timestamp}] ✅ Prediction committed (${commitmentHash})`);
+    console.log(`  Result: ${JSON.stringify(prediction)}`);
+    console.log(`  Reveal in ${REVEAL_DELAY_MS / 1000}s`);
+
+    setTimeout(() => {
+      revealPrediction(prediction, salt, commitmentHash);
+    }, REVEAL_DELAY_MS);
   } catch (error) {
-    // TODO: Fix error handling - Linera mutations return 200 with transaction hash in error.response.error
-    // This is actually a success (transaction submitted) but graphql-request treats it as error
-    // Should parse error.response.error for {"data":"<tx_hash>"} and treat as success
-    // For now, predictions ARE working despite the error message
     console.error(`[${timestamp}] ❌ Failed to trigger prediction:`);
     console.error(error);
   }
@@ -64,8 +240,10 @@ async function main() {
   console.log('  AlphaBot Scheduler - Just-in-Time Oracle');
   console.log('='.repeat(60));
   console.log('');
+  console.log(`  Bot:         ${BOT_ID}`);
   console.log(`  Strategy:    ${STRATEGY}`);
   console.log(`  Schedule:    ${CRON_SCHEDULE}`);
+  console.log(`  Horizon:     ${HORIZON_MS}ms (reveal after ${REVEAL_DELAY_MS}ms)`);
   console.log(`  GraphQL URL: ${LINERA_GRAPHQL_URL}`);
   console.log('');
   console.log('='.repeat(60));
@@ -55,7 +55,7 @@ echo "   - external-service-mirror/.env: INFERENCE_API_KEY=your_key"
 echo ""
 echo "3. Start Linera localnet and deploy contract:"
 echo "   make linera-local && make wallet-init"
-echo "   cd contracts && linera project publish-and-create bot-state --json-argument '\"my-bot\"'"
+echo "   cd contracts && linera project publish-and-create bot-state --json-parameters '{}' --json-argument '{\"bot_id\": \"my-bot\"}'"
 echo ""
 echo "4. Start services with Docker Compose:"
 echo "   docker compose up -d"